@@ -0,0 +1,118 @@
+//! Channel hop between log watcher pipeline stages: tokio's bounded mpsc, as used by
+//! the watcher, against a bounded crossbeam channel drained on a blocking thread.
+//!
+//! Run with `cargo bench --bench pipeline`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+
+/// Same as `PIPELINE_CHANNEL_CAPACITY` in `log_watcher.rs`
+const CAPACITY: usize = 256;
+
+/// Messages pushed through a channel per throughput sample
+const BATCH_SIZES: [u64; 2] = [1_000, 5_000];
+
+fn runtime() -> Runtime {
+    tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap()
+}
+
+/// Producer task to consumer task, like reader -> parser -> emitter
+fn tokio_batch(rt: &Runtime, messages: u64) -> u64 {
+    rt.block_on(async move {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(CAPACITY);
+        let producer = tokio::spawn(async move {
+            for i in 0..messages {
+                tx.send(i).await.unwrap();
+            }
+        });
+        let mut sum = 0;
+        while let Some(i) = rx.recv().await {
+            sum += i;
+        }
+        producer.await.unwrap();
+        sum
+    })
+}
+
+/// Producer thread to consumer thread, the `spawn_blocking` + `crossbeam_channel` alternative
+fn crossbeam_batch(messages: u64) -> u64 {
+    let (tx, rx) = crossbeam_channel::bounded(CAPACITY);
+    let producer = std::thread::spawn(move || {
+        for i in 0..messages {
+            tx.send(i).unwrap();
+        }
+    });
+    let sum = rx.iter().sum();
+    producer.join().unwrap();
+    sum
+}
+
+fn throughput(c: &mut Criterion) {
+    let rt = runtime();
+    let mut group = c.benchmark_group("pipeline_throughput");
+    for messages in BATCH_SIZES {
+        group.throughput(Throughput::Elements(messages));
+        group.bench_with_input(BenchmarkId::new("tokio_mpsc", messages), &messages, |b, &n| {
+            b.iter(|| tokio_batch(&rt, n))
+        });
+        group.bench_with_input(BenchmarkId::new("crossbeam", messages), &messages, |b, &n| {
+            b.iter(|| crossbeam_batch(n))
+        });
+    }
+    group.finish();
+}
+
+/// Time for one message to reach a waiting stage and an answer to come back
+fn round_trip(c: &mut Criterion) {
+    let rt = runtime();
+    let mut group = c.benchmark_group("pipeline_round_trip");
+
+    group.bench_function("tokio_mpsc", |b| {
+        b.iter_custom(|iters| {
+            rt.block_on(async move {
+                let (ping_tx, mut ping_rx) = tokio::sync::mpsc::channel::<u64>(CAPACITY);
+                let (pong_tx, mut pong_rx) = tokio::sync::mpsc::channel::<u64>(CAPACITY);
+                tokio::spawn(async move {
+                    while let Some(i) = ping_rx.recv().await {
+                        pong_tx.send(i).await.unwrap();
+                    }
+                });
+
+                let start = Instant::now();
+                for i in 0..iters {
+                    ping_tx.send(i).await.unwrap();
+                    pong_rx.recv().await.unwrap();
+                }
+                start.elapsed()
+            })
+        })
+    });
+
+    group.bench_function("crossbeam", |b| {
+        b.iter_custom(|iters| {
+            let (ping_tx, ping_rx) = crossbeam_channel::bounded::<u64>(CAPACITY);
+            let (pong_tx, pong_rx) = crossbeam_channel::bounded::<u64>(CAPACITY);
+            let echo = std::thread::spawn(move || {
+                for i in ping_rx {
+                    pong_tx.send(i).unwrap();
+                }
+            });
+
+            let start = Instant::now();
+            for i in 0..iters {
+                ping_tx.send(i).unwrap();
+                pong_rx.recv().unwrap();
+            }
+            let elapsed: Duration = start.elapsed();
+            drop(ping_tx);
+            echo.join().unwrap();
+            elapsed
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, throughput, round_trip);
+criterion_main!(benches);
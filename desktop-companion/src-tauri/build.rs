@@ -1,3 +1,4 @@
 fn main() {
+    built::write_built_file().expect("Failed to acquire build-time information");
     tauri_build::build()
 }
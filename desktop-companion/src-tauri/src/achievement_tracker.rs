@@ -0,0 +1,165 @@
+use crate::error::AppResultExt;
+use crate::log_parser::LogLine;
+use crate::store;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter};
+
+/// Store key for cumulative stats evaluated by achievements
+const PROGRESS_KEY: &str = "achievement_progress";
+
+/// Store key for achievement ID -> unlock time
+const UNLOCKED_KEY: &str = "achievements_unlocked";
+
+/// What has to happen for an achievement to unlock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AchievementCondition {
+    QuestsCompleted(u32),
+    RaidsSurvived(u32),
+    TraderLevelReached { trader: String, level: u8 },
+    /// Consecutive days with at least one game event
+    SessionStreak(u32),
+}
+
+/// A personal milestone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Achievement {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: AchievementCondition,
+}
+
+/// An achievement with its unlock state
+#[derive(Debug, Clone, Serialize)]
+pub struct AchievementStatus {
+    #[serde(flatten)]
+    pub achievement: Achievement,
+    pub unlocked: bool,
+    pub unlocked_at: Option<DateTime<Utc>>,
+}
+
+/// Cumulative stats the conditions are checked against
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AchievementProgress {
+    quests_completed: u32,
+    raids_survived: u32,
+    trader_levels: HashMap<String, u8>,
+    session_streak: u32,
+    last_session_day: Option<NaiveDate>,
+}
+
+fn achievement(id: &str, name: &str, description: &str, condition: AchievementCondition) -> Achievement {
+    Achievement {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        condition,
+    }
+}
+
+/// All achievements the tracker knows about
+pub fn achievements() -> Vec<Achievement> {
+    use AchievementCondition::*;
+
+    vec![
+        achievement("quests_10", "Errand Runner", "Complete 10 quests", QuestsCompleted(10)),
+        achievement("quests_50", "Reliable Contractor", "Complete 50 quests", QuestsCompleted(50)),
+        achievement("quests_100", "Kappa Hopeful", "Complete 100 quests", QuestsCompleted(100)),
+        achievement("survived_10", "Survivor", "Survive 10 raids", RaidsSurvived(10)),
+        achievement("survived_100", "Chad", "Survive 100 raids", RaidsSurvived(100)),
+        achievement(
+            "prapor_4",
+            "Prapor's Finest",
+            "Reach loyalty level 4 with Prapor",
+            TraderLevelReached { trader: "Prapor".to_string(), level: 4 },
+        ),
+        achievement(
+            "mechanic_4",
+            "Mechanic's Favourite",
+            "Reach loyalty level 4 with Mechanic",
+            TraderLevelReached { trader: "Mechanic".to_string(), level: 4 },
+        ),
+        achievement("streak_7", "Dedicated", "Play 7 days in a row", SessionStreak(7)),
+    ]
+}
+
+impl AchievementProgress {
+    fn meets(&self, condition: &AchievementCondition) -> bool {
+        match condition {
+            AchievementCondition::QuestsCompleted(n) => self.quests_completed >= *n,
+            AchievementCondition::RaidsSurvived(n) => self.raids_survived >= *n,
+            AchievementCondition::TraderLevelReached { trader, level } => {
+                self.trader_levels.get(trader).is_some_and(|l| l >= level)
+            }
+            AchievementCondition::SessionStreak(n) => self.session_streak >= *n,
+        }
+    }
+
+    /// Extend or restart the daily streak
+    fn record_day(&mut self, today: NaiveDate) {
+        match self.last_session_day {
+            Some(day) if day == today => return,
+            Some(day) if day.succ_opt() == Some(today) => self.session_streak += 1,
+            _ => self.session_streak = 1,
+        }
+        self.last_session_day = Some(today);
+    }
+
+    fn apply(&mut self, line: &LogLine) {
+        match line {
+            LogLine::QuestCompleted { .. } => self.quests_completed += 1,
+            LogLine::RaidEnded { survived: true, .. } => self.raids_survived += 1,
+            LogLine::TraderLevelUp { trader, level } => {
+                let current = self.trader_levels.entry(trader.clone()).or_default();
+                *current = (*current).max(*level);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Update cumulative stats with a parsed line and unlock any newly met achievements
+pub fn on_log_line(app: &AppHandle, line: &LogLine) {
+    let mut progress: AchievementProgress = store::load(app, PROGRESS_KEY).unwrap_or_default();
+    progress.record_day(Utc::now().date_naive());
+    progress.apply(line);
+    store::save(app, PROGRESS_KEY, &progress).log_and_ignore("Failed to persist achievement progress");
+
+    let mut unlocked: HashMap<String, DateTime<Utc>> = store::load(app, UNLOCKED_KEY).unwrap_or_default();
+    let newly_unlocked: Vec<Achievement> = achievements()
+        .into_iter()
+        .filter(|a| !unlocked.contains_key(&a.id) && progress.meets(&a.condition))
+        .collect();
+
+    if newly_unlocked.is_empty() {
+        return;
+    }
+
+    for achievement in &newly_unlocked {
+        unlocked.insert(achievement.id.clone(), Utc::now());
+    }
+    store::save(app, UNLOCKED_KEY, &unlocked).log_and_ignore("Failed to persist unlocked achievements");
+
+    for achievement in newly_unlocked {
+        let _ = app.emit("achievement-unlocked", achievement);
+    }
+}
+
+/// List every achievement with its unlock state
+pub fn get_achievements(app: &AppHandle) -> Vec<AchievementStatus> {
+    let unlocked: HashMap<String, DateTime<Utc>> = store::load(app, UNLOCKED_KEY).unwrap_or_default();
+
+    achievements()
+        .into_iter()
+        .map(|achievement| {
+            let unlocked_at = unlocked.get(&achievement.id).copied();
+            AchievementStatus {
+                achievement,
+                unlocked: unlocked_at.is_some(),
+                unlocked_at,
+            }
+        })
+        .collect()
+}
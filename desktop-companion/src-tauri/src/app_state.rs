@@ -1,15 +1,86 @@
+use crate::deep_link::DeepLinkPayload;
+use crate::log_watcher::WatcherHandle;
+use crate::quest_tracker::{QuestCompletion, QuestStats};
+use crate::session_tracker::SessionStats;
+use crate::supabase_realtime::RealtimeHandle;
+use crate::sync_queue::SyncQueue;
+use crate::system_tray::ConnectionStatus;
+use crate::types::HotkeyBindings;
+use chrono::{DateTime, Utc};
+use rodio::OutputStreamHandle;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::atomic::{AtomicI32, AtomicU32, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use tauri::async_runtime::JoinHandle;
+use tauri::PhysicalSize;
+use tokio_util::sync::CancellationToken;
+
+/// Store key for the persisted `AppConfig`
+pub const CONFIG_STORE_KEY: &str = "app_config";
+
+/// Wrapper whose `Debug` output hides the value, for credentials and tokens
+pub struct DebugRedacted<T>(pub T);
+
+impl<T> fmt::Debug for DebugRedacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+/// Redact an optional secret while still showing whether it is set
+fn redact_option<T>(value: &Option<T>) -> Option<DebugRedacted<&T>> {
+    value.as_ref().map(DebugRedacted)
+}
 
 /// Application configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     pub log_directory: Option<String>,
     pub supabase_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub supabase_key: Option<String>,
     pub auto_start: bool,
     pub notifications_enabled: bool,
     pub sync_enabled: bool,
+    pub health_check_interval_secs: u64,
+    /// Upper bound on Supabase writes per second while draining the sync queue
+    pub sync_rate_limit_per_second: f64,
+    pub hotkey_bindings: HotkeyBindings,
+    /// Sound effect volume, 0.0–1.0
+    pub notification_volume: f32,
+    pub window_bounds: Option<WindowBounds>,
+    pub tray_click_action: TrayClickAction,
+    /// Keep the main window hidden in the tray on launch
+    pub start_minimized: bool,
+    /// Hide the window to the tray instead of quitting when it is closed
+    pub minimize_to_tray: bool,
+    /// Gap between the window and the screen edge when snapped to a corner (logical pixels)
+    pub window_corner_margin: u32,
+    pub watcher_batch_interval_ms: u64,
+    pub max_buffered_events: usize,
+    /// Log file names containing any of these substrings are watched, in addition to
+    /// the `notifications` and `application` logs the watcher always reads
+    pub log_file_patterns: Vec<String>,
+    /// Also watch subdirectories of the log directory
+    pub recursive_log_watch: bool,
+    /// Files larger than this are memory-mapped for tail reads
+    pub mmap_threshold_bytes: u64,
+    /// Defaults to `%APPDATA%\TarkovQuestTracker\backups` when unset
+    pub backup_directory: Option<String>,
+    /// Minutes between automatic backups (0 disables them)
+    pub backup_interval_mins: u64,
+    pub webhook_url: Option<String>,
+    pub webhook_enabled: bool,
+    /// Shown in webhook posts
+    pub player_name: Option<String>,
+    /// Opt-in anonymous usage statistics
+    pub telemetry_enabled: bool,
+    pub telemetry_endpoint: Option<String>,
+    /// Set once the first-run consent prompt has been answered
+    pub telemetry_consent_asked: bool,
 }
 
 impl Default for AppConfig {
@@ -21,23 +92,286 @@ impl Default for AppConfig {
             auto_start: false,
             notifications_enabled: true,
             sync_enabled: true,
+            health_check_interval_secs: 30,
+            sync_rate_limit_per_second: 10.0,
+            hotkey_bindings: HotkeyBindings::new(),
+            notification_volume: 0.8,
+            window_bounds: None,
+            tray_click_action: TrayClickAction::default(),
+            start_minimized: false,
+            minimize_to_tray: true,
+            window_corner_margin: 16,
+            watcher_batch_interval_ms: 100,
+            max_buffered_events: 50,
+            log_file_patterns: vec!["notifications".to_string(), "application".to_string()],
+            recursive_log_watch: false,
+            mmap_threshold_bytes: 1024 * 1024,
+            backup_directory: None,
+            backup_interval_mins: 60,
+            webhook_url: None,
+            webhook_enabled: false,
+            player_name: None,
+            telemetry_enabled: false,
+            telemetry_endpoint: None,
+            telemetry_consent_asked: false,
         }
     }
 }
 
+impl AppConfig {
+    /// Resolve the key sent by the frontend, which never sees the stored one:
+    /// a missing key keeps `stored`, an empty one clears it
+    pub fn merge_supabase_key(&mut self, stored: Option<String>) {
+        match self.supabase_key.as_deref() {
+            None => self.supabase_key = stored,
+            Some("") => self.supabase_key = None,
+            Some(_) => {}
+        }
+    }
+}
+
+impl fmt::Debug for AppConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppConfig")
+            .field("log_directory", &self.log_directory)
+            .field("supabase_url", &self.supabase_url)
+            .field("supabase_key", &redact_option(&self.supabase_key))
+            .field("auto_start", &self.auto_start)
+            .field("notifications_enabled", &self.notifications_enabled)
+            .field("sync_enabled", &self.sync_enabled)
+            .field("health_check_interval_secs", &self.health_check_interval_secs)
+            .field("sync_rate_limit_per_second", &self.sync_rate_limit_per_second)
+            .field("hotkey_bindings", &self.hotkey_bindings)
+            .field("notification_volume", &self.notification_volume)
+            .field("window_bounds", &self.window_bounds)
+            .field("tray_click_action", &self.tray_click_action)
+            .field("start_minimized", &self.start_minimized)
+            .field("minimize_to_tray", &self.minimize_to_tray)
+            .field("window_corner_margin", &self.window_corner_margin)
+            .field("watcher_batch_interval_ms", &self.watcher_batch_interval_ms)
+            .field("max_buffered_events", &self.max_buffered_events)
+            .field("log_file_patterns", &self.log_file_patterns)
+            .field("recursive_log_watch", &self.recursive_log_watch)
+            .field("mmap_threshold_bytes", &self.mmap_threshold_bytes)
+            .field("backup_directory", &self.backup_directory)
+            .field("backup_interval_mins", &self.backup_interval_mins)
+            // Discord-style webhook URLs embed their token
+            .field("webhook_url", &redact_option(&self.webhook_url))
+            .field("webhook_enabled", &self.webhook_enabled)
+            .field("player_name", &self.player_name)
+            .field("telemetry_enabled", &self.telemetry_enabled)
+            .field("telemetry_endpoint", &self.telemetry_endpoint)
+            .field("telemetry_consent_asked", &self.telemetry_consent_asked)
+            .finish()
+    }
+}
+
+/// `AppConfig` as sent to the frontend, with the Supabase key withheld
+#[derive(Debug, Clone, Serialize)]
+pub struct SafeAppConfig {
+    #[serde(flatten)]
+    config: AppConfig,
+    pub supabase_key_set: bool,
+}
+
+impl From<AppConfig> for SafeAppConfig {
+    fn from(mut config: AppConfig) -> Self {
+        let supabase_key_set = config.supabase_key.take().is_some_and(|key| !key.is_empty());
+        Self {
+            config,
+            supabase_key_set,
+        }
+    }
+}
+
+/// Saved outer position and size of the main window (physical pixels)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// What a left click on the tray icon does
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum TrayClickAction {
+    AlwaysShow,
+    /// Hide the window if it is visible and focused, show it otherwise
+    #[default]
+    Toggle,
+    ShowMenu,
+}
+
 /// Watcher status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WatcherStatus {
     Stopped,
     Running,
+    Reconnecting,
     Error(String),
 }
 
+/// Overall verdict of `AppState::health_check`
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+/// Sync queue depth above which the app reports itself degraded
+pub const DEGRADED_SYNC_QUEUE_DEPTH: usize = 100;
+
+/// Gap since the last log event above which a running watcher reports itself degraded
+pub const DEGRADED_EVENT_AGE_SECS: u64 = 10 * 60;
+
+/// Liveness snapshot for monitoring tools
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub watcher_running: bool,
+    pub supabase_connected: bool,
+    /// Seconds since the watcher last saw a log change; `None` before the first one
+    pub last_event_age_secs: Option<u64>,
+    pub sync_queue_depth: usize,
+    /// Resident set size of this process
+    pub memory_rss_bytes: u64,
+}
+
+/// Resident memory of the current process, or 0 if it can't be read
+fn process_rss_bytes() -> u64 {
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return 0;
+    };
+    let mut system = sysinfo::System::new();
+    system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).map_or(0, |process| process.memory())
+}
+
+/// Completions kept in `QuestCache`
+pub const QUEST_CACHE_CAPACITY: usize = 50;
+
+/// Bounded, newest-first list of recent quest completions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCache {
+    entries: VecDeque<QuestCompletion>,
+    capacity: usize,
+}
+
+impl QuestCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Add the newest completion, evicting the oldest beyond capacity
+    pub fn push(&mut self, completion: QuestCompletion) {
+        self.entries.push_front(completion);
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Up to `limit` completions, newest first
+    pub fn recent(&self, limit: usize) -> Vec<QuestCompletion> {
+        self.entries.iter().take(limit).cloned().collect()
+    }
+}
+
+/// Raid lifecycle as shown in the tray menu; the single record of the current raid
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub enum RaidStatus {
+    #[default]
+    Idle,
+    InRaid { map: String, started_at: DateTime<Utc> },
+    /// `map` is `None` when the raid's start wasn't seen
+    Ended { survived: bool, map: Option<String> },
+}
+
+impl RaidStatus {
+    /// Map of the raid in progress, or of the one that just ended
+    pub fn map(&self) -> Option<&str> {
+        match self {
+            RaidStatus::Idle => None,
+            RaidStatus::InRaid { map, .. } => Some(map),
+            RaidStatus::Ended { map, .. } => map.as_deref(),
+        }
+    }
+}
+
 /// Global application state
 pub struct AppState {
     pub config: Arc<Mutex<AppConfig>>,
     pub watcher_status: Arc<Mutex<WatcherStatus>>,
     pub is_watching: Arc<Mutex<bool>>,
+    pub watcher: Arc<Mutex<Option<WatcherHandle>>>,
+    pub sync_queue: Arc<SyncQueue>,
+    pub last_synced_at: Arc<Mutex<Option<DateTime<Utc>>>>,
+    pub realtime: Arc<Mutex<Option<RealtimeHandle>>>,
+    pub connected: Arc<Mutex<bool>>,
+    pub connection_latency_ms: Arc<Mutex<Option<u64>>>,
+    /// Status last shown in the tray
+    pub connection_status: Arc<Mutex<ConnectionStatus>>,
+    pub game_pid: Arc<Mutex<Option<u32>>>,
+    pub game_monitor: Arc<Mutex<Option<JoinHandle<()>>>>,
+    pub is_primary_instance: Arc<Mutex<bool>>,
+    pub pending_deep_link: Arc<Mutex<Option<DeepLinkPayload>>>,
+    pub session_stats: Arc<Mutex<SessionStats>>,
+    pub quest_stats: Arc<Mutex<QuestStats>>,
+    pub quest_cache: Arc<Mutex<QuestCache>>,
+    pub raid_status: Arc<Mutex<RaidStatus>>,
+    pub skill_levels: Arc<Mutex<HashMap<String, f32>>>,
+    /// Trader name -> loyalty level
+    pub trader_levels: Arc<Mutex<HashMap<String, u8>>>,
+    /// Fence reputation × 100
+    pub fence_reputation: Arc<AtomicI32>,
+    /// Item ID -> count found in raid this session
+    pub found_items: Arc<Mutex<HashMap<String, u32>>>,
+    /// Insured items returned this session, kept apart from found-in-raid counts
+    pub insurance_returns: Arc<AtomicU32>,
+    /// Items found in raid across all sessions
+    pub items_found_in_raid: Arc<AtomicU64>,
+    /// Log reads skipped because the file looked binary or half-written
+    pub corrupted_reads: Arc<AtomicU32>,
+    pub audio_output: Arc<Mutex<Option<OutputStreamHandle>>>,
+    /// Window size to restore when leaving compact mode
+    pub compact_restore_size: Arc<Mutex<Option<PhysicalSize<u32>>>>,
+    /// Stops polling for a game install that wasn't found at startup
+    pub installation_watch: Arc<Mutex<Option<CancellationToken>>>,
+    /// Token the frontend must echo back to `reset_app_to_defaults`
+    pub reset_token: Arc<Mutex<Option<String>>>,
+    /// Character name from the most recent log header that had one
+    pub character_name: Arc<Mutex<Option<String>>>,
+}
+
+/// Handles without a useful `Debug` (watcher, sync queue, realtime, audio) are left out
+impl fmt::Debug for AppState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppState")
+            .field("config", &self.config)
+            .field("watcher_status", &self.watcher_status)
+            .field("is_watching", &self.is_watching)
+            .field("last_synced_at", &self.last_synced_at)
+            .field("connected", &self.connected)
+            .field("connection_latency_ms", &self.connection_latency_ms)
+            .field("connection_status", &self.connection_status)
+            .field("game_pid", &self.game_pid)
+            .field("is_primary_instance", &self.is_primary_instance)
+            .field("pending_deep_link", &self.pending_deep_link)
+            .field("session_stats", &self.session_stats)
+            .field("quest_stats", &self.quest_stats)
+            .field("raid_status", &self.raid_status)
+            .field("skill_levels", &self.skill_levels)
+            .field("trader_levels", &self.trader_levels)
+            .field("fence_reputation", &self.fence_reputation)
+            .field("items_found_in_raid", &self.items_found_in_raid)
+            .field("insurance_returns", &self.insurance_returns)
+            .field("corrupted_reads", &self.corrupted_reads)
+            .field("reset_token", &redact_option(&*self.reset_token.lock().unwrap()))
+            .field("character_name", &self.character_name)
+            .finish_non_exhaustive()
+    }
 }
 
 impl AppState {
@@ -46,9 +380,57 @@ impl AppState {
             config: Arc::new(Mutex::new(AppConfig::default())),
             watcher_status: Arc::new(Mutex::new(WatcherStatus::Stopped)),
             is_watching: Arc::new(Mutex::new(false)),
+            watcher: Arc::new(Mutex::new(None)),
+            sync_queue: Arc::new(SyncQueue::new()),
+            last_synced_at: Arc::new(Mutex::new(None)),
+            realtime: Arc::new(Mutex::new(None)),
+            connected: Arc::new(Mutex::new(false)),
+            connection_latency_ms: Arc::new(Mutex::new(None)),
+            connection_status: Arc::new(Mutex::new(ConnectionStatus::default())),
+            game_pid: Arc::new(Mutex::new(None)),
+            game_monitor: Arc::new(Mutex::new(None)),
+            is_primary_instance: Arc::new(Mutex::new(true)),
+            pending_deep_link: Arc::new(Mutex::new(None)),
+            session_stats: Arc::new(Mutex::new(SessionStats::default())),
+            quest_stats: Arc::new(Mutex::new(QuestStats::default())),
+            quest_cache: Arc::new(Mutex::new(QuestCache::new(QUEST_CACHE_CAPACITY))),
+            raid_status: Arc::new(Mutex::new(RaidStatus::Idle)),
+            skill_levels: Arc::new(Mutex::new(HashMap::new())),
+            trader_levels: Arc::new(Mutex::new(HashMap::new())),
+            fence_reputation: Arc::new(AtomicI32::new(0)),
+            found_items: Arc::new(Mutex::new(HashMap::new())),
+            insurance_returns: Arc::new(AtomicU32::new(0)),
+            items_found_in_raid: Arc::new(AtomicU64::new(0)),
+            corrupted_reads: Arc::new(AtomicU32::new(0)),
+            audio_output: Arc::new(Mutex::new(None)),
+            compact_restore_size: Arc::new(Mutex::new(None)),
+            installation_watch: Arc::new(Mutex::new(None)),
+            reset_token: Arc::new(Mutex::new(None)),
+            character_name: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Return config, progress and connection state to first-launch values
+    pub fn reset(&self) {
+        self.set_config(AppConfig::default());
+        self.set_watcher_status(WatcherStatus::Stopped);
+        self.set_watching(false);
+        self.sync_queue.clear();
+        *self.last_synced_at.lock().unwrap() = None;
+        *self.session_stats.lock().unwrap() = SessionStats::default();
+        self.set_quest_stats(QuestStats::default());
+        *self.quest_cache.lock().unwrap() = QuestCache::new(QUEST_CACHE_CAPACITY);
+        self.set_raid_status(RaidStatus::Idle);
+        self.skill_levels.lock().unwrap().clear();
+        self.trader_levels.lock().unwrap().clear();
+        self.fence_reputation.store(0, Ordering::Relaxed);
+        self.found_items.lock().unwrap().clear();
+        self.insurance_returns.store(0, Ordering::Relaxed);
+        self.items_found_in_raid.store(0, Ordering::Relaxed);
+        self.corrupted_reads.store(0, Ordering::Relaxed);
+        *self.character_name.lock().unwrap() = None;
+    }
+
     pub fn get_config(&self) -> AppConfig {
         self.config.lock().unwrap().clone()
     }
@@ -72,4 +454,149 @@ impl AppState {
     pub fn set_watching(&self, watching: bool) {
         *self.is_watching.lock().unwrap() = watching;
     }
+
+    pub fn get_last_synced_at(&self) -> Option<DateTime<Utc>> {
+        *self.last_synced_at.lock().unwrap()
+    }
+
+    pub fn set_last_synced_at(&self, timestamp: DateTime<Utc>) {
+        *self.last_synced_at.lock().unwrap() = Some(timestamp);
+    }
+
+    pub fn is_connected(&self) -> bool {
+        *self.connected.lock().unwrap()
+    }
+
+    pub fn set_connected(&self, connected: bool) {
+        *self.connected.lock().unwrap() = connected;
+    }
+
+    pub fn get_connection_latency_ms(&self) -> Option<u64> {
+        *self.connection_latency_ms.lock().unwrap()
+    }
+
+    pub fn set_connection_latency_ms(&self, latency_ms: Option<u64>) {
+        *self.connection_latency_ms.lock().unwrap() = latency_ms;
+    }
+
+    pub fn get_connection_status(&self) -> ConnectionStatus {
+        self.connection_status.lock().unwrap().clone()
+    }
+
+    pub fn set_connection_status(&self, status: ConnectionStatus) {
+        *self.connection_status.lock().unwrap() = status;
+    }
+
+    pub fn get_game_pid(&self) -> Option<u32> {
+        *self.game_pid.lock().unwrap()
+    }
+
+    pub fn set_game_pid(&self, pid: Option<u32>) {
+        *self.game_pid.lock().unwrap() = pid;
+    }
+
+    pub fn is_primary_instance(&self) -> bool {
+        *self.is_primary_instance.lock().unwrap()
+    }
+
+    pub fn set_primary_instance(&self, primary: bool) {
+        *self.is_primary_instance.lock().unwrap() = primary;
+    }
+
+    /// Stop polling for the game installation, if it is still running
+    pub fn cancel_installation_watch(&self) {
+        if let Some(cancel) = self.installation_watch.lock().unwrap().take() {
+            cancel.cancel();
+        }
+    }
+
+    pub fn get_session_stats(&self) -> SessionStats {
+        self.session_stats.lock().unwrap().clone()
+    }
+
+    pub fn get_quest_stats(&self) -> QuestStats {
+        *self.quest_stats.lock().unwrap()
+    }
+
+    pub fn set_quest_stats(&self, stats: QuestStats) {
+        *self.quest_stats.lock().unwrap() = stats;
+    }
+
+    pub fn get_recent_completions(&self, limit: usize) -> Vec<QuestCompletion> {
+        self.quest_cache.lock().unwrap().recent(limit)
+    }
+
+    pub fn get_raid_status(&self) -> RaidStatus {
+        self.raid_status.lock().unwrap().clone()
+    }
+
+    pub fn set_raid_status(&self, status: RaidStatus) {
+        *self.raid_status.lock().unwrap() = status;
+    }
+
+    /// Evaluate watcher, sync and connection health
+    pub fn health_check(&self) -> HealthReport {
+        let watcher_status = self.get_watcher_status();
+        let watcher_running = watcher_status == WatcherStatus::Running;
+        let last_event_age_secs = self
+            .watcher
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(WatcherHandle::last_event_at)
+            .map(|at| (Utc::now() - at).num_seconds().max(0) as u64);
+        let sync_queue_depth = self.sync_queue.depth();
+
+        // Quiet logs only matter while the watcher is supposed to be seeing them
+        let stale_events = watcher_running && last_event_age_secs.is_some_and(|age| age > DEGRADED_EVENT_AGE_SECS);
+        let status = if matches!(watcher_status, WatcherStatus::Error(_)) {
+            HealthStatus::Unhealthy
+        } else if sync_queue_depth > DEGRADED_SYNC_QUEUE_DEPTH || stale_events {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+
+        HealthReport {
+            status,
+            watcher_running,
+            supabase_connected: self.is_connected(),
+            last_event_age_secs,
+            sync_queue_depth,
+            memory_rss_bytes: process_rss_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_key(key: Option<&str>) -> AppConfig {
+        AppConfig {
+            supabase_key: key.map(str::to_string),
+            ..AppConfig::default()
+        }
+    }
+
+    #[test]
+    fn missing_supabase_key_keeps_the_stored_one() {
+        let mut config = config_with_key(None);
+        config.merge_supabase_key(Some("stored".to_string()));
+        assert_eq!(config.supabase_key.as_deref(), Some("stored"));
+    }
+
+    #[test]
+    fn empty_supabase_key_clears_the_stored_one() {
+        let mut config = config_with_key(Some(""));
+        config.merge_supabase_key(Some("stored".to_string()));
+        assert_eq!(config.supabase_key, None);
+    }
+
+    #[test]
+    fn new_supabase_key_replaces_the_stored_one() {
+        let mut config = config_with_key(Some("new"));
+        config.merge_supabase_key(Some("stored".to_string()));
+        assert_eq!(config.supabase_key.as_deref(), Some("new"));
+    }
 }
@@ -0,0 +1,60 @@
+use winreg::enums::*;
+use winreg::RegKey;
+
+/// Registry key holding per-user startup entries
+const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+
+/// Value name for this app under the Run key
+const RUN_VALUE_NAME: &str = "TarkovQuestCompanion";
+
+/// Launch argument that starts the app hidden in the tray
+pub const MINIMIZED_ARG: &str = "--minimized";
+
+/// Register the app to start with Windows
+pub fn enable_auto_start(minimize_on_start: bool) -> Result<(), String> {
+    let exe = std::env::current_exe()
+        .map_err(|e| format!("Failed to resolve executable path: {}", e))?;
+
+    let mut command = format!("\"{}\"", exe.display());
+    if minimize_on_start {
+        command.push(' ');
+        command.push_str(MINIMIZED_ARG);
+    }
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let (run_key, _) = hkcu
+        .create_subkey(RUN_KEY)
+        .map_err(|e| format!("Failed to open startup registry key: {}", e))?;
+
+    run_key
+        .set_value(RUN_VALUE_NAME, &command)
+        .map_err(|e| format!("Failed to write startup entry: {}", e))
+}
+
+/// Remove the app from Windows startup
+pub fn disable_auto_start() -> Result<(), String> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let run_key = hkcu
+        .open_subkey_with_flags(RUN_KEY, KEY_SET_VALUE)
+        .map_err(|e| format!("Failed to open startup registry key: {}", e))?;
+
+    match run_key.delete_value(RUN_VALUE_NAME) {
+        Ok(()) => Ok(()),
+        // Already disabled
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to remove startup entry: {}", e)),
+    }
+}
+
+/// Check whether the app is registered to start with Windows
+pub fn is_auto_start_enabled() -> bool {
+    RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey(RUN_KEY)
+        .and_then(|key| key.get_value::<String, _>(RUN_VALUE_NAME))
+        .is_ok()
+}
+
+/// Check whether the current process was launched with `--minimized`
+pub fn launched_minimized() -> bool {
+    std::env::args().any(|arg| arg == MINIMIZED_ARG)
+}
@@ -0,0 +1,128 @@
+use crate::app_state::AppState;
+use crate::error::{AppError, AppResult, AppResultExt};
+use crate::export::{self, ImportResult};
+use crate::tarkov_paths;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tokio::time::Duration;
+
+/// Number of backups kept after pruning
+const MAX_BACKUPS: usize = 10;
+
+/// How often a disabled backup task re-checks the config
+const DISABLED_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A backup file on disk
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupInfo {
+    pub path: String,
+    pub file_name: String,
+    pub size_bytes: u64,
+    pub created_at: String,
+}
+
+/// Configured backup directory, defaulting to `%APPDATA%\TarkovQuestTracker\backups`
+pub fn backup_directory(app: &AppHandle) -> AppResult<PathBuf> {
+    if let Some(dir) = app.state::<AppState>().get_config().backup_directory {
+        return Ok(PathBuf::from(dir));
+    }
+
+    let app_data = tarkov_paths::appdata_dir()
+        .ok_or_else(|| AppError::invalid_config("APPDATA is not set; configure backup_directory"))?;
+    Ok(app_data.join("TarkovQuestTracker").join("backups"))
+}
+
+fn is_backup_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "json")
+        && path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("backup_"))
+}
+
+/// Write the current quest records to `backup_YYYYMMDD_HHMMSS.json` and prune old backups
+pub fn create_backup(app: &AppHandle) -> AppResult<PathBuf> {
+    let dir = backup_directory(app)?;
+    std::fs::create_dir_all(&dir)?;
+
+    let json = export::export_to_json(&export::load_records(app))?;
+    let path = dir.join(format!("backup_{}.json", Utc::now().format("%Y%m%d_%H%M%S")));
+    std::fs::write(&path, json)?;
+
+    prune_backups(&dir)?;
+    Ok(path)
+}
+
+/// Backups in the backup directory, newest first
+pub fn list_backups(app: &AppHandle) -> AppResult<Vec<BackupInfo>> {
+    let dir = backup_directory(app)?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups: Vec<(DateTime<Utc>, BackupInfo)> = std::fs::read_dir(&dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_backup_file(path))
+        .filter_map(|path| {
+            let metadata = std::fs::metadata(&path).ok()?;
+            let created: DateTime<Utc> = metadata.modified().ok()?.into();
+            Some((created, BackupInfo {
+                file_name: path.file_name()?.to_string_lossy().into_owned(),
+                path: path.display().to_string(),
+                size_bytes: metadata.len(),
+                created_at: created.to_rfc3339(),
+            }))
+        })
+        .collect();
+
+    backups.sort_by_key(|(created, _)| std::cmp::Reverse(*created));
+    Ok(backups.into_iter().map(|(_, info)| info).collect())
+}
+
+/// Delete all but the newest `MAX_BACKUPS` backups
+fn prune_backups(dir: &Path) -> AppResult<()> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_backup_file(path))
+        .collect();
+
+    // Timestamped names sort chronologically
+    files.sort();
+    let excess = files.len().saturating_sub(MAX_BACKUPS);
+    for path in files.into_iter().take(excess) {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Merge a backup into the current quest records
+pub fn restore_from_backup(app: &AppHandle, path: &str) -> AppResult<ImportResult> {
+    let json = std::fs::read_to_string(path)?;
+    let mut records = export::load_records(app);
+    let result = export::import_from_json(&mut records, &json)?;
+    export::save_records(app, &records)?;
+    Ok(result)
+}
+
+/// Back up on `AppConfig.backup_interval_mins` (0 disables).
+///
+/// The interval is re-read after every backup so config changes apply
+/// without a restart.
+pub fn spawn_backup_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let interval_mins = app.state::<AppState>().get_config().backup_interval_mins;
+            if interval_mins == 0 {
+                tokio::time::sleep(DISABLED_POLL_INTERVAL).await;
+                continue;
+            }
+
+            tokio::time::sleep(Duration::from_secs(interval_mins * 60)).await;
+            create_backup(&app).emit_or_log(&app);
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use crate::error::{AppError, AppResult};
+use crate::export::{QuestRecord, QuestRecordStatus};
+use crate::session_tracker::SessionStats;
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Build a Discord-friendly checklist, e.g. `✓ Debut (Prapor)`
+pub fn format_quest_summary(quest_ids: &[String], records: &[QuestRecord]) -> String {
+    quest_ids
+        .iter()
+        .map(|id| match records.iter().find(|r| &r.id == id) {
+            Some(record) => {
+                let mark = if record.status == QuestRecordStatus::Completed { '✓' } else { '✗' };
+                format!("{} {} ({})", mark, record.name, record.trader)
+            }
+            None => format!("✗ {}", id),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format the current session's totals as plain text
+pub fn format_session_stats(stats: &SessionStats) -> String {
+    format!(
+        "Raids survived: {}/{}\nKills: {}\nQuests completed: {}",
+        stats.raids_survived, stats.raids_started, stats.kills, stats.quests_completed
+    )
+}
+
+/// Write text to the system clipboard
+pub fn write_text(app: &AppHandle, text: String) -> AppResult<()> {
+    app.clipboard().write_text(text).map_err(|e| {
+        AppError::new("CLIPBOARD_ERROR", "Failed to write to clipboard").with_details(e.to_string())
+    })
+}
@@ -0,0 +1,53 @@
+use crate::app_state::AppState;
+use crate::supabase::SupabaseClient;
+use crate::system_tray::{self, ConnectionStatus};
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::time::Duration;
+
+/// Lower bound on the ping interval to avoid hammering Supabase
+const MIN_INTERVAL_SECS: u64 = 5;
+
+/// Store a ping outcome, emitting `connection-status-changed` when connectivity flips
+pub fn record_ping(app: &AppHandle, latency: Option<u64>) {
+    let state = app.state::<AppState>();
+    state.set_connection_latency_ms(latency);
+
+    let connected = latency.is_some();
+    if connected != state.is_connected() {
+        state.set_connected(connected);
+
+        let status = if connected {
+            ConnectionStatus::Connected
+        } else {
+            ConnectionStatus::Disconnected
+        };
+
+        let _ = app.emit("connection-status-changed", status.clone());
+        if let Err(e) = system_tray::update_tray_icon_status(app, status) {
+            eprintln!("Failed to update tray icon: {}", e);
+        }
+    }
+}
+
+/// Spawn a background task that pings Supabase and tracks connectivity.
+///
+/// The interval is re-read from `AppConfig` on every iteration so config
+/// changes take effect without restarting the task.
+pub fn spawn_connection_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let state = app.state::<AppState>();
+            let config = state.get_config();
+
+            let latency = match SupabaseClient::from_config(&config) {
+                Some(client) => client.ping().await.ok(),
+                None => None,
+            };
+
+            record_ping(&app, latency);
+
+            let interval = config.health_check_interval_secs.max(MIN_INTERVAL_SECS);
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    });
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter};
+use url::Url;
+use winreg::enums::*;
+use winreg::RegKey;
+
+/// Custom URI scheme handled by the app
+pub const SCHEME: &str = "tarkovqt";
+
+/// Registry key for the scheme under the current user's classes
+const CLASSES_KEY: &str = "Software\\Classes\\tarkovqt";
+
+/// Parsed `tarkovqt://` link sent to the frontend
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkPayload {
+    pub scheme: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+}
+
+/// Register the `tarkovqt` scheme so Windows launches this executable for it
+pub fn register_scheme() -> Result<(), String> {
+    let exe = std::env::current_exe()
+        .map_err(|e| format!("Failed to resolve executable path: {}", e))?;
+    let command = format!("\"{}\" \"%1\"", exe.display());
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+
+    // Skip the write when the registration already points at this executable
+    let existing = hkcu
+        .open_subkey(format!("{}\\shell\\open\\command", CLASSES_KEY))
+        .and_then(|key| key.get_value::<String, _>(""))
+        .ok();
+    if existing.as_deref() == Some(command.as_str()) {
+        return Ok(());
+    }
+
+    let (scheme_key, _) = hkcu
+        .create_subkey(CLASSES_KEY)
+        .map_err(|e| format!("Failed to create URI scheme key: {}", e))?;
+    scheme_key
+        .set_value("", &"URL:Tarkov Quest Tracker")
+        .and_then(|_| scheme_key.set_value("URL Protocol", &""))
+        .map_err(|e| format!("Failed to write URI scheme key: {}", e))?;
+
+    let (command_key, _) = scheme_key
+        .create_subkey("shell\\open\\command")
+        .map_err(|e| format!("Failed to create URI command key: {}", e))?;
+    command_key
+        .set_value("", &command)
+        .map_err(|e| format!("Failed to write URI command: {}", e))
+}
+
+/// Find a `tarkovqt://` URI among command-line arguments
+pub fn find_uri_in_args(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let prefix = format!("{}://", SCHEME);
+    args.into_iter()
+        .find(|arg| arg.to_ascii_lowercase().starts_with(&prefix))
+}
+
+/// Parse a supported deep link.
+///
+/// Supported paths: `/quest/<id>`, `/trader/<name>` and `/import?data=<base64-json>`.
+pub fn parse_deep_link(uri: &str) -> Option<DeepLinkPayload> {
+    let url = Url::parse(uri).ok()?;
+    if url.scheme() != SCHEME {
+        return None;
+    }
+
+    // `tarkovqt://quest/<id>` parses "quest" as the host
+    let host = url.host_str().unwrap_or_default();
+    let path = format!("/{}{}", host, url.path()).trim_end_matches('/').to_string();
+    let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    let supported = match segments.as_slice() {
+        ["quest", id] | ["trader", id] => !id.is_empty(),
+        ["import"] => query.contains_key("data"),
+        _ => false,
+    };
+
+    supported.then(|| DeepLinkPayload {
+        scheme: SCHEME.to_string(),
+        path,
+        query,
+    })
+}
+
+/// Parse a URI and emit it to the frontend as a `deep-link` event
+pub fn handle_uri(app: &AppHandle, uri: &str) -> Option<DeepLinkPayload> {
+    match parse_deep_link(uri) {
+        Some(payload) => {
+            let _ = app.emit("deep-link", payload.clone());
+            Some(payload)
+        }
+        None => {
+            eprintln!("Ignoring unsupported deep link: {}", uri);
+            None
+        }
+    }
+}
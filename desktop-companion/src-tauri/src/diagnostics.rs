@@ -0,0 +1,210 @@
+use crate::app_state::{AppState, WatcherStatus};
+use crate::error::{AppError, AppResult};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use sysinfo::{CpuRefreshKind, Disk, Disks, System};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Host environment details attached to support requests
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SystemInfo {
+    pub os_name: String,
+    pub os_version: String,
+    pub cpu_brand: String,
+    pub total_memory_mb: u64,
+    pub free_memory_mb: u64,
+    pub disk_free_bytes: HashMap<String, u64>,
+    pub app_version: String,
+    pub tauri_version: String,
+}
+
+/// Compile-time constants generated by `built` in `build.rs`
+mod built_info {
+    include!(concat!(env!("OUT_DIR"), "/built.rs"));
+}
+
+/// Identity of the running backend build
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BuildInfo {
+    pub version: String,
+    pub git_hash: Option<String>,
+    pub build_date: String,
+    pub target_triple: String,
+}
+
+pub fn get_build_info() -> BuildInfo {
+    BuildInfo {
+        version: built_info::PKG_VERSION.to_string(),
+        git_hash: built_info::GIT_COMMIT_HASH.map(str::to_string),
+        build_date: built_info::BUILT_TIME_UTC.to_string(),
+        target_triple: built_info::TARGET.to_string(),
+    }
+}
+
+/// Point-in-time view of the companion's health for bug reports
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DiagnosticsSnapshot {
+    pub captured_at: String,
+    pub system: SystemInfo,
+    pub watcher_status: WatcherStatus,
+    pub is_watching: bool,
+    pub connected: bool,
+    pub connection_latency_ms: Option<u64>,
+    pub sync_queue_depth: usize,
+    pub game_running: bool,
+    pub items_found_in_raid: u64,
+    pub corrupted_reads: u32,
+}
+
+/// Space on the drive holding the log directory
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DiskSpaceInfo {
+    pub drive: String,
+    pub free_bytes: u64,
+    pub total_bytes: u64,
+    pub log_dir_size_bytes: u64,
+}
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Free space below which `low-disk-space` is emitted
+pub const LOW_DISK_SPACE_BYTES: u64 = 500 * BYTES_PER_MB;
+
+/// Directories whose drives are reported in `SystemInfo.disk_free_bytes`
+fn relevant_directories(app: &AppHandle) -> Vec<PathBuf> {
+    let config = app.state::<AppState>().get_config();
+
+    let mut directories = Vec::new();
+    if let Some(log_directory) = config.log_directory {
+        directories.push(PathBuf::from(log_directory));
+    }
+    if let Ok(app_data) = app.path().app_data_dir() {
+        directories.push(app_data);
+    }
+    directories
+}
+
+/// Disk that holds `path`, picking the most specific mount point
+fn disk_for<'a>(disks: &'a Disks, path: &Path) -> Option<&'a Disk> {
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+}
+
+/// Total size of all files under `dir`
+fn directory_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok().map(|metadata| (entry.path(), metadata)))
+        .map(|(path, metadata)| {
+            if metadata.is_dir() {
+                directory_size(&path)
+            } else {
+                metadata.len()
+            }
+        })
+        .sum()
+}
+
+/// Drive name, free and total bytes for the disk holding `path`
+fn drive_space(path: &Path) -> AppResult<(String, u64, u64)> {
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disk_for(&disks, path).ok_or_else(|| {
+        AppError::new("DISK_NOT_FOUND", "No disk found for path").with_details(path.display().to_string())
+    })?;
+
+    Ok((
+        disk.mount_point().display().to_string(),
+        disk.available_space(),
+        disk.total_space(),
+    ))
+}
+
+/// Disk usage for the drive containing `log_directory`
+pub fn get_disk_space(log_directory: &str) -> AppResult<DiskSpaceInfo> {
+    let dir = Path::new(log_directory);
+    if !dir.is_dir() {
+        return Err(AppError::file_not_found(log_directory));
+    }
+
+    let (drive, free_bytes, total_bytes) = drive_space(dir)?;
+    Ok(DiskSpaceInfo {
+        drive,
+        free_bytes,
+        total_bytes,
+        log_dir_size_bytes: directory_size(dir),
+    })
+}
+
+/// Emit `low-disk-space` when the drive holding `log_directory` is nearly full
+pub fn check_low_disk_space(app: &AppHandle, log_directory: &str) {
+    let Ok((drive, free_bytes, total_bytes)) = drive_space(Path::new(log_directory)) else {
+        return;
+    };
+
+    if free_bytes < LOW_DISK_SPACE_BYTES {
+        let _ = app.emit("low-disk-space", DiskSpaceInfo {
+            drive,
+            free_bytes,
+            total_bytes,
+            log_dir_size_bytes: 0,
+        });
+    }
+}
+
+/// Collect OS, hardware and version details
+pub fn get_system_info(app: &AppHandle) -> SystemInfo {
+    let mut system = System::new();
+    system.refresh_memory();
+    system.refresh_cpu_list(CpuRefreshKind::nothing());
+
+    let disks = Disks::new_with_refreshed_list();
+    let mut disk_free_bytes = HashMap::new();
+    for directory in relevant_directories(app) {
+        if let Some(disk) = disk_for(&disks, &directory) {
+            disk_free_bytes.insert(disk.mount_point().display().to_string(), disk.available_space());
+        }
+    }
+
+    SystemInfo {
+        os_name: System::name().unwrap_or_else(|| "Unknown".to_string()),
+        os_version: System::long_os_version()
+            .or_else(System::os_version)
+            .unwrap_or_else(|| "Unknown".to_string()),
+        cpu_brand: system
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .unwrap_or_default(),
+        total_memory_mb: system.total_memory() / BYTES_PER_MB,
+        free_memory_mb: system.available_memory() / BYTES_PER_MB,
+        disk_free_bytes,
+        app_version: app.package_info().version.to_string(),
+        tauri_version: tauri::VERSION.to_string(),
+    }
+}
+
+/// Capture system info together with the watcher, sync and connection state
+pub fn collect_snapshot(app: &AppHandle) -> DiagnosticsSnapshot {
+    let state = app.state::<AppState>();
+
+    DiagnosticsSnapshot {
+        captured_at: chrono::Utc::now().to_rfc3339(),
+        system: get_system_info(app),
+        watcher_status: state.get_watcher_status(),
+        is_watching: state.is_watching(),
+        connected: state.is_connected(),
+        connection_latency_ms: state.get_connection_latency_ms(),
+        sync_queue_depth: state.sync_queue.depth(),
+        game_running: state.get_game_pid().is_some(),
+        items_found_in_raid: state.items_found_in_raid.load(Ordering::Relaxed),
+        corrupted_reads: state.corrupted_reads.load(Ordering::Relaxed),
+    }
+}
@@ -1,4 +1,27 @@
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Default base delay for exponential backoff
+pub const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Retry limit for errors that don't set their own
+pub const DEFAULT_MAX_RETRIES: u32 = 5;
+
+/// Default upper bound for exponential backoff
+pub const DEFAULT_RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Error severity, ordered from least to most severe
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ErrorSeverity {
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
 
 /// Error structure for desktop app operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,8 +29,13 @@ pub struct AppError {
     pub code: String,
     pub message: String,
     pub details: Option<String>,
+    pub severity: ErrorSeverity,
     pub recoverable: bool,
     pub retry_count: u32,
+    pub max_retries: u32,
+    /// Server-requested wait before retrying (HTTP `Retry-After`)
+    pub retry_after_secs: Option<u32>,
+    pub correlation_id: Option<String>,
 }
 
 impl AppError {
@@ -17,20 +45,60 @@ impl AppError {
             code: code.into(),
             message: message.into(),
             details: None,
+            severity: ErrorSeverity::Error,
             recoverable: true,
             retry_count: 0,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_after_secs: None,
+            correlation_id: None,
         }
     }
 
+    /// Create a new warning-level error
+    pub fn warning(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(code, message).with_severity(ErrorSeverity::Warning)
+    }
+
     /// Set error details
     pub fn with_details(mut self, details: impl Into<String>) -> Self {
         self.details = Some(details.into());
         self
     }
 
-    /// Mark error as non-recoverable
+    /// Attach a correlation ID for tracing related errors across components
+    pub fn with_correlation_id(mut self, id: impl Into<String>) -> Self {
+        self.correlation_id = Some(id.into());
+        self
+    }
+
+    /// Record the error that caused this one, inheriting its correlation ID
+    pub fn with_cause(mut self, cause: &AppError) -> Self {
+        if cause.correlation_id.is_some() {
+            self.correlation_id = cause.correlation_id.clone();
+        }
+        if self.details.is_none() {
+            self.details = Some(format!("caused by {}", cause));
+        }
+        self
+    }
+
+    /// Generate a short correlation ID (first 12 hex chars of a UUID v4)
+    pub fn generate_id() -> String {
+        let mut id = uuid::Uuid::new_v4().simple().to_string();
+        id.truncate(12);
+        id
+    }
+
+    /// Set error severity
+    pub fn with_severity(mut self, severity: ErrorSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Mark error as non-recoverable (raises severity to at least `Error`)
     pub fn non_recoverable(mut self) -> Self {
         self.recoverable = false;
+        self.severity = self.severity.max(ErrorSeverity::Error);
         self
     }
 
@@ -39,9 +107,50 @@ impl AppError {
         self.retry_count += 1;
     }
 
-    /// Check if max retries exceeded (default: 5)
+    /// Override how many retries this error allows
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Record how long the server asked us to wait
+    pub fn with_retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after_secs = Some(retry_after.as_secs() as u32);
+        self
+    }
+
+    /// Check if max retries exceeded
     pub fn max_retries_exceeded(&self) -> bool {
-        self.retry_count >= 5
+        self.retry_count >= self.max_retries
+    }
+
+    /// Log the error under `context`: a warning if recoverable, an error otherwise
+    pub fn log(&self, context: &str) {
+        if self.recoverable {
+            tracing::warn!(code = %self.code, correlation_id = ?self.correlation_id, "{}: {}", context, self);
+        } else {
+            tracing::error!(code = %self.code, correlation_id = ?self.correlation_id, "{}: {}", context, self);
+        }
+    }
+
+    /// Log the error and report it to the frontend as an `app-error` event
+    pub fn emit_to_frontend<R: Runtime>(&self, app: &AppHandle<R>) {
+        self.log("App error");
+        let _ = app.emit("app-error", self);
+    }
+
+    /// Delay before the next retry using the default backoff settings
+    pub fn next_retry_delay(&self) -> Duration {
+        self.next_retry_delay_with(DEFAULT_RETRY_BASE_DELAY, DEFAULT_RETRY_MAX_DELAY)
+    }
+
+    /// Delay before the next retry: `min(base * 2^retry_count, max_delay)` plus up to 10% jitter
+    pub fn next_retry_delay_with(&self, base: Duration, max_delay: Duration) -> Duration {
+        let multiplier = 1u32.checked_shl(self.retry_count).unwrap_or(u32::MAX);
+        let delay = base.saturating_mul(multiplier).min(max_delay);
+        let max_jitter_ms = (delay.as_millis() / 10) as u64;
+        let jitter_ms = rand::thread_rng().gen_range(0..=max_jitter_ms);
+        delay + Duration::from_millis(jitter_ms)
     }
 }
 
@@ -59,10 +168,16 @@ impl AppError {
             .with_details(format!("{}: {}", path.into(), error))
     }
 
+    pub fn watch_error(error: impl std::fmt::Display) -> Self {
+        Self::new("WATCH_ERROR", "File watcher failed")
+            .with_details(error.to_string())
+    }
+
     /// Network errors
     pub fn network_error(error: impl std::fmt::Display) -> Self {
         Self::new("NETWORK_ERROR", "Network connection failed")
             .with_details(error.to_string())
+            .with_max_retries(10)
     }
 
     pub fn timeout_error() -> Self {
@@ -73,6 +188,8 @@ impl AppError {
     pub fn auth_error(error: impl std::fmt::Display) -> Self {
         Self::new("AUTH_ERROR", "Authentication failed")
             .with_details(error.to_string())
+            .with_severity(ErrorSeverity::Fatal)
+            .with_max_retries(0)
             .non_recoverable()
     }
 
@@ -90,7 +207,8 @@ impl AppError {
 
     /// Rate limiting
     pub fn rate_limited() -> Self {
-        Self::new("RATE_LIMITED", "API rate limit exceeded, please try again later")
+        Self::warning("RATE_LIMITED", "API rate limit exceeded, please try again later")
+            .with_max_retries(3)
     }
 
     /// Configuration errors
@@ -99,7 +217,178 @@ impl AppError {
             .with_details(field.into())
             .non_recoverable()
     }
+
+    /// Serialization errors
+    pub fn parse_error(error: impl std::fmt::Display) -> Self {
+        Self::new("PARSE_ERROR", "Failed to parse data")
+            .with_details(error.to_string())
+            .non_recoverable()
+    }
+
+    /// Offline queue errors
+    pub fn sync_error(message: impl Into<String>) -> Self {
+        Self::new("SYNC_ERROR", message)
+    }
+
+    /// Persistence errors
+    pub fn store_error(error: impl std::fmt::Display) -> Self {
+        Self::new("STORE_ERROR", "Failed to access persistent store")
+            .with_details(error.to_string())
+    }
+
+    /// Internal state errors
+    pub fn state_error(error: impl std::fmt::Display) -> Self {
+        Self::new("STATE_ERROR", "Application state is unavailable")
+            .with_details(error.to_string())
+            .non_recoverable()
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)?;
+        if let Some(details) = &self.details {
+            write!(f, ": {}", details)?;
+        }
+        if let Some(id) = &self.correlation_id {
+            write!(f, " (correlation_id={})", id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+/// Conversions from foreign error types
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => Self::file_not_found(error.to_string()),
+            std::io::ErrorKind::TimedOut => Self::timeout_error().with_details(error.to_string()),
+            _ => Self::new("FILE_READ_ERROR", "Failed to read file").with_details(error.to_string()),
+        }
+    }
+}
+
+impl From<notify::Error> for AppError {
+    fn from(error: notify::Error) -> Self {
+        match error.kind {
+            notify::ErrorKind::PathNotFound => {
+                let paths = error
+                    .paths
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Self::file_not_found(paths)
+            }
+            notify::ErrorKind::Io(io_error) => io_error.into(),
+            _ => Self::watch_error(error),
+        }
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::parse_error(error)
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::timeout_error().with_details(error.to_string())
+        } else {
+            Self::network_error(error)
+        }
+    }
+}
+
+impl From<tauri_plugin_store::Error> for AppError {
+    fn from(error: tauri_plugin_store::Error) -> Self {
+        Self::store_error(error)
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(error: tauri::Error) -> Self {
+        Self::new("TAURI_ERROR", "Tauri runtime call failed").with_details(error.to_string())
+    }
+}
+
+impl<T> From<std::sync::PoisonError<T>> for AppError {
+    fn from(error: std::sync::PoisonError<T>) -> Self {
+        Self::state_error(error)
+    }
 }
 
 /// Result type alias using AppError
 pub type AppResult<T> = Result<T, AppError>;
+
+/// Helpers for results whose error should be surfaced rather than returned
+pub trait AppResultExt<T> {
+    /// Send the error to the frontend via `AppError::emit_to_frontend`, keeping the `Ok` value
+    fn ok_or_emit<R: Runtime>(self, app: &AppHandle<R>) -> Option<T>;
+
+    /// Send the error to the frontend via `AppError::emit_to_frontend`; nothing happens on `Ok`
+    fn emit_or_log<R: Runtime>(self, app: &AppHandle<R>);
+
+    /// Log the error under `context` (warning if recoverable, error otherwise) and discard the result
+    fn log_and_ignore(self, context: &str);
+}
+
+impl<T, E: Into<AppError>> AppResultExt<T> for Result<T, E> {
+    fn ok_or_emit<R: Runtime>(self, app: &AppHandle<R>) -> Option<T> {
+        match self.map_err(Into::into) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                error.emit_to_frontend(app);
+                None
+            }
+        }
+    }
+
+    fn emit_or_log<R: Runtime>(self, app: &AppHandle<R>) {
+        if let Err(error) = self.map_err(Into::into) {
+            error.emit_to_frontend(app);
+        }
+    }
+
+    fn log_and_ignore(self, context: &str) {
+        if let Err(error) = self.map_err(Into::into) {
+            error.log(context);
+        }
+    }
+}
+
+/// Run an async operation, retrying with exponential backoff on failure.
+///
+/// Gives up after `max_attempts` attempts, when the error is non-recoverable,
+/// or once the error's own retry limit is exceeded.
+pub async fn retry_with_backoff<F, Fut, T>(mut op: F, max_attempts: u32) -> AppResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = AppResult<T>>,
+{
+    let mut retries = 0;
+
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(mut error) => {
+                error.retry_count = retries;
+
+                if !error.recoverable
+                    || error.max_retries_exceeded()
+                    || retries + 1 >= max_attempts
+                {
+                    return Err(error);
+                }
+
+                tokio::time::sleep(error.next_retry_delay()).await;
+                error.increment_retry();
+                retries = error.retry_count;
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::AppResult;
+use crate::store;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// Store key for locally tracked quest records
+const STORE_KEY: &str = "quest_records";
+
+/// Progress state of a single quest
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum QuestRecordStatus {
+    Locked,
+    Available,
+    Started,
+    Completed,
+    Failed,
+}
+
+impl QuestRecordStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            QuestRecordStatus::Locked => "Locked",
+            QuestRecordStatus::Available => "Available",
+            QuestRecordStatus::Started => "Started",
+            QuestRecordStatus::Completed => "Completed",
+            QuestRecordStatus::Failed => "Failed",
+        }
+    }
+}
+
+/// Exportable quest progress entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRecord {
+    pub id: String,
+    pub name: String,
+    pub trader: String,
+    pub status: QuestRecordStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+}
+
+/// Outcome of importing quest records
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportResult {
+    pub imported: u32,
+    pub skipped: u32,
+    pub errors: Vec<String>,
+}
+
+/// Serialize quest records as pretty-printed JSON
+pub fn export_to_json(quests: &[QuestRecord]) -> AppResult<String> {
+    Ok(serde_json::to_string_pretty(quests)?)
+}
+
+/// Serialize quest records as CSV with a human-readable header row
+pub fn export_to_csv(quests: &[QuestRecord]) -> AppResult<String> {
+    let mut csv = String::from("Quest ID,Quest Name,Trader,Status,Completed At,Notes\n");
+
+    for quest in quests {
+        let completed_at = quest
+            .completed_at
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default();
+
+        let row = [
+            quest.id.as_str(),
+            quest.name.as_str(),
+            quest.trader.as_str(),
+            quest.status.label(),
+            completed_at.as_str(),
+            quest.notes.as_deref().unwrap_or_default(),
+        ]
+        .map(escape_csv_field)
+        .join(",");
+
+        csv.push_str(&row);
+        csv.push('\n');
+    }
+
+    Ok(csv)
+}
+
+/// Quote a CSV field if it contains separators, quotes or newlines
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Merge imported records into `existing`, keeping the most recently completed entry
+pub fn merge_records(existing: &mut Vec<QuestRecord>, incoming: Vec<QuestRecord>) -> ImportResult {
+    let mut result = ImportResult::default();
+
+    for record in incoming {
+        if record.id.trim().is_empty() {
+            result.errors.push(format!("Record \"{}\" has no quest ID", record.name));
+            continue;
+        }
+
+        match existing.iter_mut().find(|r| r.id == record.id) {
+            Some(current) => {
+                // Last write wins; undated records never replace dated ones
+                if record.completed_at > current.completed_at {
+                    *current = record;
+                    result.imported += 1;
+                } else {
+                    result.skipped += 1;
+                }
+            }
+            None => {
+                existing.push(record);
+                result.imported += 1;
+            }
+        }
+    }
+
+    result
+}
+
+/// Parse a JSON export and merge it into `existing`
+pub fn import_from_json(existing: &mut Vec<QuestRecord>, json: &str) -> AppResult<ImportResult> {
+    let incoming: Vec<QuestRecord> = serde_json::from_str(json)?;
+    Ok(merge_records(existing, incoming))
+}
+
+/// Load locally tracked quest records
+pub fn load_records(app: &AppHandle) -> Vec<QuestRecord> {
+    store::load(app, STORE_KEY).unwrap_or_default()
+}
+
+/// Persist locally tracked quest records
+pub fn save_records(app: &AppHandle, records: &[QuestRecord]) -> AppResult<()> {
+    store::save(app, STORE_KEY, &records)
+}
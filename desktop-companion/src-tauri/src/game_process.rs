@@ -0,0 +1,83 @@
+use crate::app_state::AppState;
+use sysinfo::{ProcessesToUpdate, System};
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::time::Duration;
+
+/// Executable names of the game client (with and without BattlEye)
+pub const GAME_PROCESS_NAMES: &[&str] = &["EscapeFromTarkov.exe", "EscapeFromTarkov_BE.exe"];
+
+/// How often the process list is polled
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Game lifecycle event payload
+#[derive(Clone, serde::Serialize)]
+pub struct GameProcessEvent {
+    pub pid: u32,
+    pub timestamp: String,
+}
+
+/// Find the PID of a running game process, if any
+pub fn find_game_process(system: &System) -> Option<u32> {
+    system
+        .processes()
+        .values()
+        .find(|process| {
+            let name = process.name().to_string_lossy();
+            GAME_PROCESS_NAMES
+                .iter()
+                .any(|game| name.eq_ignore_ascii_case(game))
+        })
+        .map(|process| process.pid().as_u32())
+}
+
+/// Poll for the game process, emitting `game-started` / `game-stopped` on changes.
+///
+/// When the game starts and `AppConfig.auto_start` is enabled, the log
+/// watcher is started for the configured log directory.
+pub fn spawn_game_monitor(app: AppHandle) -> JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let mut system = System::new();
+
+        loop {
+            system.refresh_processes(ProcessesToUpdate::All, true);
+            let current = find_game_process(&system);
+
+            let state = app.state::<AppState>();
+            let previous = state.get_game_pid();
+
+            match (previous, current) {
+                (None, Some(pid)) => {
+                    state.set_game_pid(Some(pid));
+                    let _ = app.emit("game-started", GameProcessEvent {
+                        pid,
+                        timestamp: chrono::Utc::now().to_rfc3339(),
+                    });
+
+                    let config = state.get_config();
+                    if config.auto_start && !state.is_watching() {
+                        if let Some(log_directory) = config.log_directory {
+                            if let Err(e) = crate::start_watching(&app, log_directory) {
+                                eprintln!("Failed to auto-start log watcher: {}", e);
+                            }
+                        }
+                    }
+                }
+                (Some(pid), None) => {
+                    state.set_game_pid(None);
+                    let _ = app.emit("game-stopped", GameProcessEvent {
+                        pid,
+                        timestamp: chrono::Utc::now().to_rfc3339(),
+                    });
+                }
+                (Some(old_pid), Some(new_pid)) if old_pid != new_pid => {
+                    // Game restarted between polls
+                    state.set_game_pid(Some(new_pid));
+                }
+                _ => {}
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    })
+}
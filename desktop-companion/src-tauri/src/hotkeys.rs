@@ -0,0 +1,108 @@
+use crate::app_state::{AppState, CONFIG_STORE_KEY};
+use crate::clipboard;
+use crate::error::{AppError, AppResult};
+use crate::store;
+use crate::system_tray;
+use crate::types::HotkeyBindings;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+/// Bring the main window to front
+pub const SHOW_WINDOW: &str = "show_window";
+/// Show the main window if hidden, hide it otherwise
+pub const TOGGLE_WINDOW: &str = "toggle_window";
+/// Copy the current session stats to the clipboard
+pub const COPY_SESSION_STATS: &str = "copy_session_stats";
+
+fn hotkey_error(error: impl std::fmt::Display) -> AppError {
+    AppError::new("HOTKEY_ERROR", "Failed to update global shortcut").with_details(error.to_string())
+}
+
+/// Bind `shortcut` to action `id`, replacing any previous binding for that action
+pub fn register_hotkey(app: &AppHandle, id: &str, shortcut: &str) -> AppResult<()> {
+    bind(app, id, shortcut)?;
+
+    let state = app.state::<AppState>();
+    let mut config = state.get_config();
+    config.hotkey_bindings.insert(id.to_string(), shortcut.to_string());
+    state.set_config(config.clone());
+    store::save(app, CONFIG_STORE_KEY, &config)?;
+    system_tray::refresh_tray_menu(app);
+    Ok(())
+}
+
+/// Remove the binding for action `id`
+pub fn unregister_hotkey(app: &AppHandle, id: &str) -> AppResult<()> {
+    let state = app.state::<AppState>();
+    let mut config = state.get_config();
+    let Some(shortcut) = config.hotkey_bindings.remove(id) else {
+        return Ok(());
+    };
+
+    app.global_shortcut().unregister(shortcut.as_str()).map_err(hotkey_error)?;
+    state.set_config(config.clone());
+    store::save(app, CONFIG_STORE_KEY, &config)?;
+    system_tray::refresh_tray_menu(app);
+    Ok(())
+}
+
+/// Re-register all bindings saved in `AppConfig`
+pub fn restore_hotkeys(app: &AppHandle) {
+    let bindings: HotkeyBindings = app.state::<AppState>().get_config().hotkey_bindings;
+    for (id, shortcut) in bindings {
+        if let Err(e) = bind(app, &id, &shortcut) {
+            eprintln!("Failed to restore hotkey {} ({}): {}", id, shortcut, e);
+        }
+    }
+}
+
+fn bind(app: &AppHandle, id: &str, shortcut: &str) -> AppResult<()> {
+    let shortcuts = app.global_shortcut();
+
+    // Drop the action's previous accelerator before taking the new one
+    let previous = app.state::<AppState>().get_config().hotkey_bindings.get(id).cloned();
+    if let Some(previous) = previous {
+        if shortcuts.is_registered(previous.as_str()) {
+            shortcuts.unregister(previous.as_str()).map_err(hotkey_error)?;
+        }
+    }
+
+    let action = id.to_string();
+    shortcuts
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                run_action(app, &action);
+            }
+        })
+        .map_err(hotkey_error)
+}
+
+fn run_action(app: &AppHandle, action: &str) {
+    match action {
+        SHOW_WINDOW => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+        TOGGLE_WINDOW => {
+            if let Some(window) = app.get_webview_window("main") {
+                if window.is_visible().unwrap_or(false) {
+                    let _ = window.hide();
+                } else {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+        }
+        COPY_SESSION_STATS => {
+            let stats = app.state::<AppState>().get_session_stats();
+            if let Err(e) = clipboard::write_text(app, clipboard::format_session_stats(&stats)) {
+                eprintln!("Hotkey clipboard copy failed: {}", e);
+            }
+        }
+        _ => {}
+    }
+
+    let _ = app.emit("hotkey-fired", action);
+}
@@ -1,19 +1,62 @@
+mod achievement_tracker;
 mod app_state;
+mod auto_start;
+mod backup;
+mod clipboard;
+mod connection_monitor;
+mod deep_link;
+mod diagnostics;
 mod error;
+mod export;
+mod game_process;
+mod hotkeys;
+mod log_parser;
 mod log_watcher;
+mod loot_tracker;
+mod notification_rules;
+mod notifications;
+mod profile_importer;
+mod quest_tracker;
+mod rate_limiter;
+mod session_tracker;
+mod single_instance;
+mod sound;
+mod store;
+mod supabase;
+mod supabase_realtime;
+mod sync_queue;
 mod system_tray;
+mod tarkov_api;
 mod tarkov_paths;
+mod telemetry;
+mod types;
+mod updater;
+mod webhook;
+mod window_manager;
+mod wipe_tracker;
 
-use app_state::{AppConfig, AppState, WatcherStatus};
-use tauri::{Manager, State};
+use app_state::{AppConfig, AppState, SafeAppConfig, WatcherStatus, CONFIG_STORE_KEY};
+use supabase_realtime::RealtimeConfig;
+use sync_queue::{ConflictResolution, SyncOperation};
+use tauri::{Emitter, Manager, State};
 
 // ============================================================================
 // IPC Commands
 // ============================================================================
 
 #[tauri::command]
-fn get_app_config(state: State<AppState>) -> Result<AppConfig, String> {
-    Ok(state.get_config())
+fn get_app_config(state: State<AppState>) -> Result<SafeAppConfig, String> {
+    Ok(state.get_config().into())
+}
+
+/// The only way to read the raw Supabase key; an authentication check will gate this
+#[tauri::command]
+fn get_supabase_key(state: State<AppState>) -> Result<String, String> {
+    state
+        .get_config()
+        .supabase_key
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| "Supabase key is not configured".to_string())
 }
 
 #[tauri::command]
@@ -26,82 +69,870 @@ fn update_tray_icon(
 }
 
 #[tauri::command]
-fn save_app_config(config: AppConfig, state: State<AppState>) -> Result<bool, String> {
+fn save_app_config(mut config: AppConfig, app: tauri::AppHandle, state: State<AppState>) -> Result<bool, String> {
+    config.merge_supabase_key(state.get_config().supabase_key);
+    store::save(&app, CONFIG_STORE_KEY, &config).map_err(|e| e.to_string())?;
+    if config.log_directory.is_some() {
+        state.cancel_installation_watch();
+    }
     state.set_config(config);
+    system_tray::refresh_tray_menu(&app);
     Ok(true)
 }
 
 #[tauri::command]
-fn auto_detect_log_directory() -> Result<String, String> {
-    tarkov_paths::detect_tarkov_directory()
+fn auto_detect_log_directory(state: State<AppState>) -> Result<String, String> {
+    let directory = tarkov_paths::detect_tarkov_directory()?;
+    state.cancel_installation_watch();
+    Ok(directory)
 }
 
 #[tauri::command]
-fn validate_log_directory(path: String) -> Result<bool, String> {
-    tarkov_paths::validate_log_directory(&path)
+fn detect_tarkov_installation() -> Result<tarkov_paths::TarkovInstallInfo, String> {
+    tarkov_paths::detect_tarkov_installation()
 }
 
 #[tauri::command]
-fn start_log_watcher(
-    log_directory: String,
-    app: tauri::AppHandle,
+fn list_tarkov_installations() -> Vec<tarkov_paths::RankedInstallInfo> {
+    tarkov_paths::detect_all_tarkov_directories()
+}
+
+#[tauri::command]
+fn bust_detection_cache() {
+    tarkov_paths::bust_detection_cache();
+}
+
+#[tauri::command]
+fn list_bsg_profiles() -> Result<Vec<tarkov_paths::BsgProfile>, String> {
+    tarkov_paths::list_bsg_profiles()
+}
+
+#[tauri::command]
+fn import_bsg_profile(
+    path: String,
+    user_id: String,
+    dry_run: Option<bool>,
     state: State<AppState>,
-) -> Result<String, String> {
-    if state.is_watching() {
+) -> Result<profile_importer::ProfileImportSummary, String> {
+    let import = profile_importer::import_profile(std::path::Path::new(&path)).map_err(|e| e.to_string())?;
+    let importable_quests = profile_importer::importable_quests(&import);
+    let dry_run = dry_run.unwrap_or(false);
+
+    if !dry_run {
+        profile_importer::queue_quests(&state.sync_queue, &user_id, &importable_quests);
+    }
+
+    Ok(profile_importer::ProfileImportSummary {
+        level: import.level,
+        quests_found: import.quests.len(),
+        importable_quests,
+        trader_standings: import.trader_standings,
+        dry_run,
+    })
+}
+
+#[tauri::command]
+fn validate_log_directory(
+    path: String,
+    state: State<AppState>,
+) -> Result<tarkov_paths::LogDirectoryValidation, String> {
+    tarkov_paths::validate_log_directory(&path, state.get_config().recursive_log_watch)
+}
+
+#[tauri::command]
+fn validate_log_file(path: String) -> Result<tarkov_paths::LogFileValidation, String> {
+    tarkov_paths::validate_log_file(&path)
+}
+
+/// Start the log watcher and record it in `AppState` (shared by IPC and background tasks)
+pub(crate) fn start_watching(app: &tauri::AppHandle, log_directory: String) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    // Held for the whole check-and-start so concurrent calls can't both start a watcher
+    let mut watcher = state.watcher.lock().unwrap();
+    if watcher.is_some() {
         return Err("Watcher is already running".to_string());
     }
 
-    log_watcher::start_log_watcher(log_directory.clone(), app)?;
+    let config = state.get_config();
+    let handle = log_watcher::start_log_watcher(
+        log_directory.clone(),
+        app.clone(),
+        log_watcher::LogWatcherConfig::from_app_config(&config),
+    )
+    .map_err(|e| e.to_string())?;
     
+    *watcher = Some(handle);
     state.set_watching(true);
     state.set_watcher_status(WatcherStatus::Running);
+    telemetry::track(app, telemetry::TelemetryEvent::WatcherStarted);
 
     Ok(format!("Started watching: {}", log_directory))
 }
 
+/// `log_path` may be a log directory or a single log file; `log_directory` is its
+/// former name, still accepted from older frontends
 #[tauri::command]
-fn stop_log_watcher(state: State<AppState>) -> Result<bool, String> {
-    if !state.is_watching() {
-        return Ok(false);
+fn start_log_watcher(
+    log_path: Option<String>,
+    log_directory: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<String, String> {
+    let log_directory = log_path.or(log_directory).unwrap_or_default();
+    if log_directory.trim().is_empty() {
+        if let Ok(detected) = tarkov_paths::detect_tarkov_directory() {
+            let state = app.state::<AppState>();
+            let mut config = state.get_config();
+            config.log_directory = Some(detected.clone());
+            store::save(&app, CONFIG_STORE_KEY, &config).map_err(|e| e.to_string())?;
+            state.set_config(config);
+            state.cancel_installation_watch();
+            return start_watching(&app, detected);
+        }
     }
 
-    log_watcher::stop_log_watcher()?;
+    start_watching(&app, log_directory)
+}
+
+#[tauri::command]
+fn stop_log_watcher(app: tauri::AppHandle, state: State<AppState>) -> Result<bool, String> {
+    let mut watcher = state.watcher.lock().unwrap();
+    let Some(handle) = watcher.take() else {
+        return Ok(false);
+    };
+
+    log_watcher::stop_log_watcher(handle, "Stopped by user").map_err(|e| e.to_string())?;
     
     state.set_watching(false);
     state.set_watcher_status(WatcherStatus::Stopped);
+    telemetry::track(&app, telemetry::TelemetryEvent::WatcherStopped);
 
     Ok(true)
 }
 
+#[tauri::command]
+fn restart_log_watcher(
+    new_directory: Option<String>,
+    app: tauri::AppHandle,
+    state: State<AppState>,
+) -> Result<String, String> {
+    let mut config = state.get_config();
+    if let Some(directory) = new_directory {
+        config.log_directory = Some(directory);
+        store::save(&app, CONFIG_STORE_KEY, &config).map_err(|e| e.to_string())?;
+        state.cancel_installation_watch();
+        state.set_config(config.clone());
+    }
+    let Some(log_directory) = config.log_directory.clone() else {
+        return Err(error::AppError::invalid_config("log_directory").to_string());
+    };
+
+    // The watcher lock is held across the stop and the start so a concurrent
+    // start can't slip in between. The old task winds down asynchronously, so it
+    // is stopped without `watcher-stopped`, which could otherwise arrive after
+    // the new watcher's `watcher-started`
+    let mut watcher = state.watcher.lock().unwrap();
+    if let Some(handle) = watcher.take() {
+        handle.stop_for_restart();
+    }
+
+    match log_watcher::start_log_watcher(
+        log_directory.clone(),
+        app.clone(),
+        log_watcher::LogWatcherConfig::from_app_config(&config),
+    ) {
+        Ok(handle) => {
+            *watcher = Some(handle);
+            state.set_watching(true);
+            state.set_watcher_status(WatcherStatus::Running);
+            Ok(format!("Restarted watching: {}", log_directory))
+        }
+        Err(e) => {
+            state.set_watching(false);
+            state.set_watcher_status(WatcherStatus::Error(e.to_string()));
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+fn set_watcher_batch_interval(ms: u64, app: tauri::AppHandle, state: State<AppState>) -> Result<(), String> {
+    if !(log_watcher::MIN_BATCH_INTERVAL_MS..=log_watcher::MAX_BATCH_INTERVAL_MS).contains(&ms) {
+        return Err(error::AppError::invalid_config(format!(
+            "watcher_batch_interval_ms must be between {} and {}",
+            log_watcher::MIN_BATCH_INTERVAL_MS,
+            log_watcher::MAX_BATCH_INTERVAL_MS
+        ))
+        .to_string());
+    }
+
+    let mut config = state.get_config();
+    config.watcher_batch_interval_ms = ms;
+    store::save(&app, CONFIG_STORE_KEY, &config).map_err(|e| e.to_string())?;
+    state.set_config(config);
+
+    if let Some(watcher) = state.watcher.lock().unwrap().as_ref() {
+        watcher.set_batch_interval(ms);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn test_log_file_pattern(file_name: String, state: State<AppState>) -> Result<bool, String> {
+    // The watcher's patterns, including the logs it always watches
+    let patterns = log_watcher::LogWatcherConfig::from_app_config(&state.get_config()).file_patterns;
+    Ok(log_watcher::is_watched_log(std::path::Path::new(&file_name), &patterns))
+}
+
+#[tauri::command]
+fn list_log_files(directory: String, offset: usize, limit: usize) -> Result<Vec<log_watcher::LogFileInfo>, String> {
+    log_watcher::list_log_files(&directory, offset, limit).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn read_log_tail(path: String, max_lines: usize) -> Result<Vec<String>, String> {
+    log_watcher::read_log_tail(&path, max_lines).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn search_log_events(
+    directory: String,
+    pattern: String,
+    max_results: usize,
+) -> Result<Vec<log_watcher::SearchResult>, String> {
+    tauri::async_runtime::spawn_blocking(move || log_watcher::search_log_events(&directory, &pattern, max_results))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Characters in the confirmation token for `reset_app_to_defaults`
+const RESET_TOKEN_LENGTH: usize = 6;
+
+#[tauri::command]
+fn request_reset_token(state: State<AppState>) -> String {
+    use rand::Rng;
+
+    let token: String = rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(RESET_TOKEN_LENGTH)
+        .map(|c| char::from(c).to_ascii_uppercase())
+        .collect();
+    *state.reset_token.lock().unwrap() = Some(token.clone());
+    token
+}
+
+#[tauri::command]
+fn reset_app_to_defaults(
+    confirmation_token: String,
+    delete_backups: bool,
+    app: tauri::AppHandle,
+    state: State<AppState>,
+) -> Result<(), String> {
+    let expected = state.reset_token.lock().unwrap().take();
+    if expected.as_deref() != Some(confirmation_token.as_str()) {
+        return Err(error::AppError::invalid_config("confirmation_token").to_string());
+    }
+
+    if let Some(handle) = state.watcher.lock().unwrap().take() {
+        handle.stop("App reset");
+    }
+
+    // Resolve before the config (and any custom backup_directory) is wiped
+    let backup_dir = backup::backup_directory(&app).ok();
+
+    state.reset();
+    store::clear(&app).map_err(|e| e.to_string())?;
+
+    if delete_backups {
+        if let Some(dir) = backup_dir.filter(|dir| dir.exists()) {
+            std::fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+    }
+
+    system_tray::refresh_tray_menu(&app);
+    let _ = app.emit("app-reset", ());
+    Ok(())
+}
+
+#[tauri::command]
+fn get_app_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+#[tauri::command]
+fn get_build_info() -> diagnostics::BuildInfo {
+    diagnostics::get_build_info()
+}
+
+#[tauri::command]
+fn get_system_info(app: tauri::AppHandle) -> diagnostics::SystemInfo {
+    diagnostics::get_system_info(&app)
+}
+
+#[tauri::command]
+fn get_diagnostics_snapshot(app: tauri::AppHandle) -> diagnostics::DiagnosticsSnapshot {
+    diagnostics::collect_snapshot(&app)
+}
+
+#[tauri::command]
+fn get_health_report(state: State<AppState>) -> app_state::HealthReport {
+    state.health_check()
+}
+
+#[tauri::command]
+async fn ping_supabase(app: tauri::AppHandle) -> Result<supabase::PingResult, String> {
+    let url = app
+        .state::<AppState>()
+        .get_config()
+        .supabase_url
+        .filter(|url| !url.is_empty())
+        .ok_or_else(|| error::AppError::invalid_config("supabase_url").to_string())?;
+
+    let result = supabase::ping_url(&url).await;
+    connection_monitor::record_ping(&app, result.reachable.then_some(result.latency_ms));
+    Ok(result)
+}
+
+#[tauri::command]
+async fn validate_supabase_connection(
+    app: tauri::AppHandle,
+) -> Result<supabase::SupabaseValidationResult, String> {
+    let client = supabase::SupabaseClient::from_config(&app.state::<AppState>().get_config())
+        .ok_or_else(|| error::AppError::invalid_config("supabase_url").to_string())?;
+    Ok(client.validate().await)
+}
+
+#[tauri::command]
+async fn force_sync(app: tauri::AppHandle) -> Result<sync_queue::SyncSummary, String> {
+    sync_queue::force_sync(&app).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn open_log_directory(app: tauri::AppHandle) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
+
+    let directory = app
+        .state::<AppState>()
+        .get_config()
+        .log_directory
+        .ok_or_else(|| error::AppError::invalid_config("log_directory").to_string())?;
+
+    if !std::path::Path::new(&directory).is_dir() {
+        return Err(error::AppError::file_not_found(directory).to_string());
+    }
+
+    app.opener()
+        .open_path(directory, None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn reveal_file_in_directory(path: String, app: tauri::AppHandle) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
+
+    if !std::path::Path::new(&path).exists() {
+        return Err(error::AppError::file_not_found(path).to_string());
+    }
+
+    app.opener().reveal_item_in_dir(path).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn get_disk_space(app: tauri::AppHandle) -> Result<diagnostics::DiskSpaceInfo, String> {
+    let directory = app
+        .state::<AppState>()
+        .get_config()
+        .log_directory
+        .ok_or_else(|| error::AppError::invalid_config("log_directory").to_string())?;
+
+    diagnostics::get_disk_space(&directory).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn get_recent_completions(limit: usize, state: State<AppState>) -> Vec<quest_tracker::QuestCompletion> {
+    state.get_recent_completions(limit)
+}
+
+#[tauri::command]
+fn get_watcher_buffer_depth(state: State<AppState>) -> Result<usize, String> {
+    Ok(state
+        .watcher
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map_or(0, |watcher| watcher.buffer_depth()))
+}
+
 #[tauri::command]
 fn get_watcher_status(state: State<AppState>) -> Result<WatcherStatus, String> {
     Ok(state.get_watcher_status())
 }
 
+#[tauri::command]
+fn get_watcher_details(state: State<AppState>) -> log_watcher::WatcherDetails {
+    let status = state.get_watcher_status();
+    let watcher = state.watcher.lock().unwrap();
+    match watcher.as_ref() {
+        Some(handle) => log_watcher::WatcherDetails {
+            status,
+            directory: Some(handle.directory().to_string()),
+            started_at: Some(handle.started_at().to_rfc3339()),
+            events_processed: handle.events_processed(),
+            last_event_at: handle.last_event_at().map(|at| at.to_rfc3339()),
+            buffer_depth: handle.buffer_depth(),
+            pipeline_depth: handle.pipeline_depth(),
+        },
+        None => log_watcher::WatcherDetails {
+            status,
+            directory: state.get_config().log_directory,
+            started_at: None,
+            events_processed: 0,
+            last_event_at: None,
+            buffer_depth: 0,
+            pipeline_depth: 0,
+        },
+    }
+}
+
+#[tauri::command]
+fn get_watcher_log_statistics(state: State<AppState>) -> Result<log_watcher::WatcherLogStats, String> {
+    state
+        .watcher
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|handle| handle.log_statistics())
+        .ok_or_else(|| "Watcher is not running".to_string())
+}
+
+#[tauri::command]
+fn enqueue_sync_item(
+    operation: SyncOperation,
+    payload: serde_json::Value,
+    state: State<AppState>,
+) -> Result<String, String> {
+    Ok(state.sync_queue.enqueue(operation, payload))
+}
+
+#[tauri::command]
+fn get_sync_queue_depth(state: State<AppState>) -> Result<usize, String> {
+    Ok(state.sync_queue.depth())
+}
+
+#[tauri::command]
+fn resolve_conflict(
+    item_id: String,
+    resolution: ConflictResolution,
+    state: State<AppState>,
+) -> Result<(), String> {
+    state
+        .sync_queue
+        .resolve_conflict(&item_id, resolution)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn start_realtime_sync(
+    user_id: String,
+    access_token: Option<String>,
+    app: tauri::AppHandle,
+    state: State<AppState>,
+) -> Result<bool, String> {
+    let config = state.get_config();
+    let (Some(supabase_url), Some(api_key)) = (config.supabase_url, config.supabase_key) else {
+        return Err("Supabase is not configured".to_string());
+    };
+
+    let mut realtime = state.realtime.lock().unwrap();
+    if let Some(existing) = realtime.take() {
+        existing.stop();
+    }
+
+    *realtime = Some(supabase_realtime::start_realtime_sync(
+        app,
+        RealtimeConfig {
+            supabase_url,
+            api_key,
+            user_id,
+            access_token,
+        },
+    ));
+
+    Ok(true)
+}
+
+#[tauri::command]
+fn stop_realtime_sync(state: State<AppState>) -> Result<bool, String> {
+    match state.realtime.lock().unwrap().take() {
+        Some(handle) => {
+            handle.stop();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[tauri::command]
+fn enable_auto_start(minimize_on_start: bool) -> Result<(), String> {
+    auto_start::enable_auto_start(minimize_on_start)
+}
+
+#[tauri::command]
+fn disable_auto_start() -> Result<(), String> {
+    auto_start::disable_auto_start()
+}
+
+#[tauri::command]
+fn is_auto_start_enabled() -> Result<bool, String> {
+    Ok(auto_start::is_auto_start_enabled())
+}
+
+#[tauri::command]
+fn is_primary_instance(state: State<AppState>) -> Result<bool, String> {
+    Ok(state.is_primary_instance())
+}
+
+#[tauri::command]
+fn take_pending_deep_link(state: State<AppState>) -> Result<Option<deep_link::DeepLinkPayload>, String> {
+    Ok(state.pending_deep_link.lock().unwrap().take())
+}
+
+#[tauri::command]
+fn export_progress_json(app: tauri::AppHandle) -> Result<String, String> {
+    export::export_to_json(&export::load_records(&app)).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn export_progress_csv(app: tauri::AppHandle) -> Result<String, String> {
+    export::export_to_csv(&export::load_records(&app)).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn import_progress_json(json: String, app: tauri::AppHandle) -> Result<export::ImportResult, String> {
+    let mut records = export::load_records(&app);
+    let result = export::import_from_json(&mut records, &json).map_err(|e| e.to_string())?;
+    export::save_records(&app, &records).map_err(|e| e.to_string())?;
+    quest_tracker::refresh_stats(&app);
+    Ok(result)
+}
+
+#[tauri::command]
+fn copy_quest_summary_to_clipboard(quest_ids: Vec<String>, app: tauri::AppHandle) -> Result<(), String> {
+    let summary = clipboard::format_quest_summary(&quest_ids, &export::load_records(&app));
+    clipboard::write_text(&app, summary).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn copy_session_stats_to_clipboard(app: tauri::AppHandle, state: State<AppState>) -> Result<(), String> {
+    let stats = clipboard::format_session_stats(&state.get_session_stats());
+    clipboard::write_text(&app, stats).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn register_hotkey(id: String, shortcut: String, app: tauri::AppHandle) -> Result<(), String> {
+    hotkeys::register_hotkey(&app, &id, &shortcut).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn unregister_hotkey(id: String, app: tauri::AppHandle) -> Result<(), String> {
+    hotkeys::unregister_hotkey(&app, &id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn send_notification(payload: notifications::NotificationPayload, app: tauri::AppHandle) -> Result<(), String> {
+    notifications::send_notification(&app, &payload).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn play_sound(effect: sound::SoundEffect, state: State<AppState>) -> Result<(), String> {
+    sound::play_sound(&state, effect).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn test_notification_sound(state: State<AppState>) -> Result<(), String> {
+    sound::play_sound(&state, sound::SoundEffect::QuestComplete).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn set_always_on_top(enabled: bool, app: tauri::AppHandle) -> Result<(), String> {
+    window_manager::set_always_on_top(&app, enabled).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn set_window_opacity(opacity: f64, app: tauri::AppHandle) -> Result<(), String> {
+    window_manager::set_window_opacity(&app, opacity).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn move_window_to_corner(corner: window_manager::WindowCorner, app: tauri::AppHandle) -> Result<(), String> {
+    window_manager::move_window_to_corner(&app, corner).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn toggle_compact_mode(app: tauri::AppHandle) -> Result<bool, String> {
+    window_manager::toggle_compact_mode(&app).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn save_window_position(app: tauri::AppHandle) -> Result<app_state::WindowBounds, String> {
+    window_manager::save_window_position(&app).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_quest_meta(quest_id: String, app: tauri::AppHandle) -> Result<tarkov_api::QuestMeta, String> {
+    tarkov_api::get_quest_meta(&app, &quest_id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn get_wipe_info() -> Result<wipe_tracker::WipeInfo, String> {
+    Ok(wipe_tracker::get_latest_wipe())
+}
+
+#[tauri::command]
+fn days_since_wipe() -> Result<u32, String> {
+    Ok(wipe_tracker::days_since_wipe())
+}
+
+#[tauri::command]
+fn get_achievements(app: tauri::AppHandle) -> Result<Vec<achievement_tracker::AchievementStatus>, String> {
+    Ok(achievement_tracker::get_achievements(&app))
+}
+
+#[tauri::command]
+fn get_session_stats(app: tauri::AppHandle) -> Result<session_tracker::SessionStatsReport, String> {
+    Ok(session_tracker::get_session_stats(&app))
+}
+
+#[tauri::command]
+fn get_session_stats_by_map(
+    app: tauri::AppHandle,
+) -> Result<std::collections::HashMap<String, session_tracker::MapStats>, String> {
+    Ok(session_tracker::get_session_stats_by_map(&app))
+}
+
+#[tauri::command]
+fn get_flea_earnings(app: tauri::AppHandle) -> u64 {
+    session_tracker::get_flea_earnings(&app)
+}
+
+#[tauri::command]
+fn get_skill_levels(app: tauri::AppHandle) -> Result<std::collections::HashMap<String, f32>, String> {
+    Ok(session_tracker::get_skill_levels(&app))
+}
+
+#[tauri::command]
+fn get_fence_reputation(app: tauri::AppHandle) -> Result<f32, String> {
+    Ok(session_tracker::get_fence_reputation(&app))
+}
+
+#[tauri::command]
+fn get_trader_levels(app: tauri::AppHandle) -> Result<std::collections::HashMap<String, u8>, String> {
+    Ok(session_tracker::get_trader_levels(&app))
+}
+
+#[tauri::command]
+fn get_found_items(app: tauri::AppHandle) -> Result<std::collections::HashMap<String, u32>, String> {
+    Ok(loot_tracker::get_found_items(&app))
+}
+
+#[tauri::command]
+fn get_items_found_count(app: tauri::AppHandle) -> u64 {
+    loot_tracker::get_items_found_count(&app)
+}
+
+#[tauri::command]
+fn get_insurance_returns_count(app: tauri::AppHandle) -> u32 {
+    loot_tracker::get_insurance_returns_count(&app)
+}
+
+#[tauri::command]
+fn clear_found_items(app: tauri::AppHandle) -> Result<(), String> {
+    loot_tracker::clear_found_items(&app);
+    Ok(())
+}
+
+#[tauri::command]
+fn reset_session_stats(app: tauri::AppHandle) -> Result<(), String> {
+    session_tracker::reset_session_stats(&app);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_notification_rules(app: tauri::AppHandle) -> Result<Vec<notification_rules::NotificationRule>, String> {
+    Ok(notification_rules::get_rules(&app))
+}
+
+#[tauri::command]
+fn save_notification_rules(
+    rules: Vec<notification_rules::NotificationRule>,
+    app: tauri::AppHandle,
+) -> Result<(), String> {
+    notification_rules::save_rules(&app, &rules).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn reset_notification_rules_to_defaults(
+    app: tauri::AppHandle,
+) -> Result<Vec<notification_rules::NotificationRule>, String> {
+    notification_rules::reset_rules(&app).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn check_for_updates() -> Result<updater::UpdateInfo, String> {
+    updater::check_for_updates().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn create_backup(app: tauri::AppHandle) -> Result<String, String> {
+    backup::create_backup(&app)
+        .map(|path| path.display().to_string())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn list_backups(app: tauri::AppHandle) -> Result<Vec<backup::BackupInfo>, String> {
+    backup::list_backups(&app).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn restore_from_backup(path: String, app: tauri::AppHandle) -> Result<export::ImportResult, String> {
+    let result = backup::restore_from_backup(&app, &path).map_err(|e| e.to_string())?;
+    quest_tracker::refresh_stats(&app);
+    Ok(result)
+}
+
+#[tauri::command]
+fn set_telemetry_consent(enabled: bool, app: tauri::AppHandle) -> Result<(), String> {
+    telemetry::set_consent(&app, enabled).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn test_webhook(app: tauri::AppHandle) -> Result<String, String> {
+    let status = webhook::test_webhook(&app).await.map_err(|e| e.to_string())?;
+    Ok(format!("HTTP {}", status))
+}
+
+#[tauri::command]
+fn is_game_running(state: State<AppState>) -> Result<bool, String> {
+    Ok(state.get_game_pid().is_some())
+}
+
+#[tauri::command]
+fn get_game_process_pid(state: State<AppState>) -> Result<Option<u32>, String> {
+    Ok(state.get_game_pid())
+}
+
+#[tauri::command]
+fn get_connection_latency_ms(state: State<AppState>) -> Result<Option<u64>, String> {
+    Ok(state.get_connection_latency_ms())
+}
+
+#[tauri::command]
+fn get_last_synced_at(state: State<AppState>) -> Result<Option<String>, String> {
+    Ok(state.get_last_synced_at().map(|t| t.to_rfc3339()))
+}
+
 // ============================================================================
 // Application Entry Point
 // ============================================================================
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let _ = tracing_subscriber::fmt().with_writer(std::io::stderr).try_init();
+
+    // Hand off to an already running instance before building the app
+    let launch_uri = deep_link::find_uri_in_args(std::env::args().skip(1));
+    let instance_listener = single_instance::acquire(launch_uri.as_deref());
+    let state = AppState::new();
+    state.set_primary_instance(instance_listener.is_some());
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
-        .manage(AppState::new())
-        .setup(|app| {
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .manage(state)
+        .setup(move |app| {
+            // Restore saved settings before anything reads them
+            if let Some(config) = store::load::<AppConfig, _>(app.handle(), CONFIG_STORE_KEY) {
+                app.state::<AppState>().set_config(config);
+            }
+            hotkeys::restore_hotkeys(app.handle());
+
+            // Let later instances bring this one to front
+            if let Some(listener) = instance_listener {
+                single_instance::listen(app.handle().clone(), listener);
+            }
+
+            // Register the tarkovqt:// scheme and handle a link we were launched with
+            if let Err(e) = deep_link::register_scheme() {
+                eprintln!("Failed to register URI scheme: {}", e);
+            }
+            if let Some(uri) = launch_uri {
+                // Kept for the frontend to pick up once its listeners are ready
+                let payload = deep_link::handle_uri(app.handle(), &uri);
+                *app.state::<AppState>().pending_deep_link.lock().unwrap() = payload;
+            }
+
+            // Keep looking for the game if it isn't installed yet
+            if app.state::<AppState>().get_config().log_directory.is_none()
+                && tarkov_paths::detect_tarkov_directory().is_err()
+            {
+                let cancel = tokio_util::sync::CancellationToken::new();
+                *app.state::<AppState>().installation_watch.lock().unwrap() = Some(cancel.clone());
+                tauri::async_runtime::spawn(tarkov_paths::watch_for_installation(app.handle().clone(), cancel));
+            }
+
             // Setup system tray
-            system_tray::setup_system_tray(app.handle())?;
+            quest_tracker::restore_quest_cache(app.handle());
+            session_tracker::restore_progression(app.handle());
+            loot_tracker::restore_items_found(app.handle());
+            let hotkey_bindings = app.state::<AppState>().get_config().hotkey_bindings;
+            system_tray::setup_system_tray(app.handle(), &hotkey_bindings)?;
+            quest_tracker::spawn_total_refresh(app.handle().clone());
+
+            // Restore offline writes and start draining them
+            sync_queue::restore_last_synced_at(app.handle());
+            app.state::<AppState>().sync_queue.attach(app.handle());
+            sync_queue::spawn_flush_task(app.handle().clone());
+
+            // Track Supabase reachability in the background
+            connection_monitor::spawn_connection_monitor(app.handle().clone());
+
+            // Watch for the game process to auto-start the log watcher
+            let game_monitor = game_process::spawn_game_monitor(app.handle().clone());
+            *app.state::<AppState>().game_monitor.lock().unwrap() = Some(game_monitor);
+
+            // Put the window back where the user left it
+            if let Err(e) = window_manager::restore_window_position(app.handle()) {
+                eprintln!("Failed to restore window position: {}", e);
+            }
+
+            // Periodically back up quest progress
+            backup::spawn_backup_task(app.handle().clone());
+
+            // Look for new releases in the background
+            updater::spawn_update_checker(app.handle().clone());
+
+            telemetry::track(app.handle(), telemetry::TelemetryEvent::AppStarted);
 
-            // Prevent window from closing (minimize to tray instead)
             if let Some(window) = app.get_webview_window("main") {
+                // Launched from Windows startup or asked to: stay in the tray
+                if auto_start::launched_minimized() || app.state::<AppState>().get_config().start_minimized {
+                    let _ = window.hide();
+                }
+
+                // Minimize to tray instead of closing, if enabled (read per event so changes apply immediately)
                 let window_clone = window.clone();
                 window.on_window_event(move |event| {
                     if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                        api.prevent_close();
-                        // Hide window to tray instead of closing
-                        let _ = window_clone.hide();
+                        if window_clone.state::<AppState>().get_config().minimize_to_tray {
+                            api.prevent_close();
+                            let _ = window_clone.hide();
+                        }
                     }
                 });
             }
@@ -110,13 +941,96 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             get_app_config,
+            get_supabase_key,
             save_app_config,
             auto_detect_log_directory,
             validate_log_directory,
+            validate_log_file,
             start_log_watcher,
             stop_log_watcher,
+            restart_log_watcher,
             get_watcher_status,
+            get_watcher_details,
+            get_watcher_log_statistics,
+            set_watcher_batch_interval,
+            get_watcher_buffer_depth,
+            test_log_file_pattern,
+            list_log_files,
+            read_log_tail,
+            search_log_events,
+            request_reset_token,
+            reset_app_to_defaults,
+            get_app_version,
+            get_build_info,
+            get_system_info,
+            get_diagnostics_snapshot,
+            get_health_report,
+            ping_supabase,
+            validate_supabase_connection,
+            force_sync,
+            open_log_directory,
+            reveal_file_in_directory,
+            get_disk_space,
+            list_bsg_profiles,
+            import_bsg_profile,
+            detect_tarkov_installation,
+            list_tarkov_installations,
+            bust_detection_cache,
+            get_recent_completions,
             update_tray_icon,
+            enqueue_sync_item,
+            get_sync_queue_depth,
+            get_last_synced_at,
+            resolve_conflict,
+            start_realtime_sync,
+            stop_realtime_sync,
+            get_connection_latency_ms,
+            is_game_running,
+            get_game_process_pid,
+            enable_auto_start,
+            disable_auto_start,
+            is_auto_start_enabled,
+            is_primary_instance,
+            take_pending_deep_link,
+            export_progress_json,
+            export_progress_csv,
+            import_progress_json,
+            copy_quest_summary_to_clipboard,
+            copy_session_stats_to_clipboard,
+            register_hotkey,
+            unregister_hotkey,
+            send_notification,
+            play_sound,
+            test_notification_sound,
+            set_always_on_top,
+            set_window_opacity,
+            move_window_to_corner,
+            toggle_compact_mode,
+            save_window_position,
+            get_quest_meta,
+            get_wipe_info,
+            days_since_wipe,
+            get_achievements,
+            get_session_stats,
+            get_session_stats_by_map,
+            get_flea_earnings,
+            reset_session_stats,
+            get_skill_levels,
+            get_fence_reputation,
+            get_trader_levels,
+            get_found_items,
+            get_items_found_count,
+            get_insurance_returns_count,
+            clear_found_items,
+            get_notification_rules,
+            save_notification_rules,
+            reset_notification_rules_to_defaults,
+            check_for_updates,
+            create_backup,
+            list_backups,
+            restore_from_backup,
+            test_webhook,
+            set_telemetry_consent,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
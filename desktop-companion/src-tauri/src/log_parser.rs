@@ -0,0 +1,455 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// Marker preceding every backend notification in the `notifications` log
+const NOTIFICATION_MARKER: &str = "Got notification | ";
+
+/// Chat message types carrying quest state changes
+const MESSAGE_TYPE_TASK_STARTED: u64 = 10;
+const MESSAGE_TYPE_TASK_FAILED: u64 = 11;
+const MESSAGE_TYPE_TASK_FINISHED: u64 = 12;
+
+/// Chat message type of a trader returning insured gear
+const MESSAGE_TYPE_INSURANCE_RETURN: u64 = 8;
+
+/// Trader IDs as they appear in chat notifications
+const TRADER_NAMES: [(&str, &str); 10] = [
+    ("54cb50c76803fa8b248b4571", "Prapor"),
+    ("54cb57776803fa99248b456e", "Therapist"),
+    ("579dc571d53a0658a154fbec", "Fence"),
+    ("58330581ace78e27b8b10cee", "Skier"),
+    ("5935c25fb3acc3127c3d8cd9", "Peacekeeper"),
+    ("5a7c2eca46aef81a7ca2145d", "Mechanic"),
+    ("5ac3b934156ae10c4430e83c", "Ragman"),
+    ("5c0647fdd443bc2504c2d371", "Jaeger"),
+    ("638f541a29ffd1183d187f57", "Lightkeeper"),
+    ("6617beeaa9cfa777ca915b7c", "Ref"),
+];
+
+/// Display name for a trader ID, or the ID itself if it isn't known
+fn trader_name(id: &str) -> String {
+    TRADER_NAMES
+        .iter()
+        .find(|(trader_id, _)| *trader_id == id)
+        .map_or(id, |(_, name)| name)
+        .to_string()
+}
+
+/// Hideout areas, in EFT `areaType` order
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub enum HideoutStation {
+    Vents,
+    Security,
+    Lavatory,
+    Stash,
+    Generator,
+    Heating,
+    WaterCollector,
+    Medstation,
+    NutritionUnit,
+    RestSpace,
+    Workbench,
+    IntelligenceCenter,
+    ShootingRange,
+    Library,
+    ScavCase,
+    Illumination,
+    HallOfFame,
+    AirFilteringUnit,
+    SolarPower,
+    BoozeGenerator,
+    BitcoinFarm,
+    ChristmasTree,
+    DefectiveWall,
+    Gym,
+    WeaponRack,
+    WeaponRackSecondary,
+    GearRack,
+    CultistCircle,
+    Unknown(String),
+}
+
+impl HideoutStation {
+    const KNOWN: [HideoutStation; 28] = [
+        HideoutStation::Vents,
+        HideoutStation::Security,
+        HideoutStation::Lavatory,
+        HideoutStation::Stash,
+        HideoutStation::Generator,
+        HideoutStation::Heating,
+        HideoutStation::WaterCollector,
+        HideoutStation::Medstation,
+        HideoutStation::NutritionUnit,
+        HideoutStation::RestSpace,
+        HideoutStation::Workbench,
+        HideoutStation::IntelligenceCenter,
+        HideoutStation::ShootingRange,
+        HideoutStation::Library,
+        HideoutStation::ScavCase,
+        HideoutStation::Illumination,
+        HideoutStation::HallOfFame,
+        HideoutStation::AirFilteringUnit,
+        HideoutStation::SolarPower,
+        HideoutStation::BoozeGenerator,
+        HideoutStation::BitcoinFarm,
+        HideoutStation::ChristmasTree,
+        HideoutStation::DefectiveWall,
+        HideoutStation::Gym,
+        HideoutStation::WeaponRack,
+        HideoutStation::WeaponRackSecondary,
+        HideoutStation::GearRack,
+        HideoutStation::CultistCircle,
+    ];
+
+    /// Map a numeric EFT `areaType`
+    pub fn from_area_type(area_type: u64) -> Self {
+        Self::KNOWN
+            .get(area_type as usize)
+            .cloned()
+            .unwrap_or_else(|| HideoutStation::Unknown(area_type.to_string()))
+    }
+
+    /// Map a station name, ignoring case, spaces and underscores
+    pub fn from_name(name: &str) -> Self {
+        let normalized: String = name
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        Self::KNOWN
+            .iter()
+            .find(|station| format!("{:?}", station).to_ascii_lowercase() == normalized)
+            .cloned()
+            .unwrap_or_else(|| HideoutStation::Unknown(name.to_string()))
+    }
+}
+
+/// An item handed back by a trader's insurance
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct InsuredItem {
+    /// Item template ID
+    pub item_id: String,
+    /// Empty when the log doesn't include it
+    pub name: String,
+}
+
+/// A game event recognised in a log line
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum LogLine {
+    QuestStarted { quest_id: String },
+    QuestFailed { quest_id: String },
+    QuestCompleted { quest_id: String },
+    /// Character level reported by the backend (drops to 1 after a wipe or reset)
+    ProfileLevel { level: u32 },
+    TraderLevelUp { trader: String, level: u8 },
+    RaidStarted { map: String, mode: String },
+    RaidEnded { survived: bool, kills: u32 },
+    HideoutUpgraded { station: HideoutStation, new_level: u8 },
+    /// EFT skill levels are fractional (progress towards the next level)
+    SkillLevelUp { skill: String, new_level: f32 },
+    /// `new_value` is `None` on older builds, which only log the delta
+    ScavKarmaChange { delta: f32, new_value: Option<f32>, reason: String },
+    ItemFoundInRaid { item_id: String, item_name: String, count: u32 },
+    InsuranceReturn { trader: String, items: Vec<InsuredItem> },
+    FleaMarketSold { item_name: String, count: u32, price_rub: u64, fee: u64 },
+}
+
+/// Which EFT log a file is; each has its own line format
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum LogType {
+    /// `notifications_*.log`: backend pushes as `Got notification | <Type> | {json}`
+    Notifications,
+    /// `application_*.log`: client lifecycle (raid creation, connection state)
+    Application,
+    Unknown,
+}
+
+impl LogType {
+    /// Classify a log file by name
+    pub fn from_path(path: &Path) -> Self {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if name.contains("notifications") {
+            LogType::Notifications
+        } else if name.contains("application") {
+            LogType::Application
+        } else {
+            LogType::Unknown
+        }
+    }
+}
+
+/// Marker of the application log line written when a raid is created
+const RAID_CREATED_MARKER: &str = "NetworkGameCreate";
+
+/// Value of a `Key: value,` field in a profile status line
+fn status_field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let marker = format!("{}: ", key);
+    let rest = &line[line.find(&marker)? + marker.len()..];
+    let value = rest.split([',', '\'']).next()?.trim();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Parse a `NetworkGameCreate` profile status line (`... RaidMode: Online, ... Location: bigmap, ...`)
+fn parse_raid_started(line: &str) -> Option<LogLine> {
+    Some(LogLine::RaidStarted {
+        map: status_field(line, "Location")?.to_string(),
+        mode: status_field(line, "RaidMode").unwrap_or("Online").to_string(),
+    })
+}
+
+/// Split `... Got notification | <Type> | {json}` into its type and JSON body
+fn parse_notification(line: &str) -> Option<(&str, Value)> {
+    let rest = &line[line.find(NOTIFICATION_MARKER)? + NOTIFICATION_MARKER.len()..];
+    let kind = rest.split('|').next()?.trim();
+
+    let json_start = rest.find('{')?;
+    let json_end = rest.rfind('}')?;
+    let body = serde_json::from_str(rest.get(json_start..=json_end)?).ok()?;
+
+    Some((kind, body))
+}
+
+/// Parse a single line from a log of the given type into a known event
+pub fn parse_log_line(log_type: LogType, line: &str) -> Option<LogLine> {
+    match log_type {
+        LogType::Notifications => parse_notification_line(line),
+        LogType::Application => parse_application_line(line),
+        LogType::Unknown => parse_application_line(line).or_else(|| parse_notification_line(line)),
+    }
+}
+
+/// Parse an `application` log line
+fn parse_application_line(line: &str) -> Option<LogLine> {
+    if line.contains(RAID_CREATED_MARKER) {
+        return parse_raid_started(line);
+    }
+    None
+}
+
+/// Parse an insurance `ChatMessageReceived` body; the returned items are in `message.items.data`
+fn parse_insurance_return(body: &Value) -> Option<LogLine> {
+    let trader_id = body["dialogId"].as_str().or_else(|| body["message"]["uid"].as_str())?;
+    let items = body["message"]["items"]["data"]
+        .as_array()?
+        .iter()
+        .filter_map(|item| {
+            Some(InsuredItem {
+                item_id: item["_tpl"].as_str()?.to_string(),
+                name: item["name"].as_str().unwrap_or_default().to_string(),
+            })
+        })
+        .collect();
+
+    Some(LogLine::InsuranceReturn {
+        trader: trader_name(trader_id),
+        items,
+    })
+}
+
+/// Parse a `notifications` log line
+fn parse_notification_line(line: &str) -> Option<LogLine> {
+    let (kind, body) = parse_notification(line)?;
+
+    match kind {
+        "ChatMessageReceived" => {
+            if body["MessageType"].as_u64()? == MESSAGE_TYPE_INSURANCE_RETURN {
+                return parse_insurance_return(&body);
+            }
+
+            // templateId is "<questId> <args...>"
+            let quest_id = body["message"]["templateId"]
+                .as_str()?
+                .split(' ')
+                .next()?
+                .to_string();
+
+            match body["MessageType"].as_u64()? {
+                MESSAGE_TYPE_TASK_STARTED => Some(LogLine::QuestStarted { quest_id }),
+                MESSAGE_TYPE_TASK_FAILED => Some(LogLine::QuestFailed { quest_id }),
+                MESSAGE_TYPE_TASK_FINISHED => Some(LogLine::QuestCompleted { quest_id }),
+                _ => None,
+            }
+        }
+        "ProfileLevelChanged" => Some(LogLine::ProfileLevel {
+            level: body["level"].as_u64()? as u32,
+        }),
+        "TraderLoyaltyChanged" => Some(LogLine::TraderLevelUp {
+            trader: body["trader"].as_str()?.to_string(),
+            level: body["level"].as_u64()? as u8,
+        }),
+        "UserMatchOver" => Some(LogLine::RaidEnded {
+            survived: body["exitStatus"].as_str()? == "Survived",
+            kills: body["kills"].as_u64().unwrap_or(0) as u32,
+        }),
+        // areaType is numeric in current builds; older ones logged the name
+        "HideoutAreaUpgraded" | "HideoutUpgrade" => {
+            let station = match &body["areaType"] {
+                Value::Number(n) => HideoutStation::from_area_type(n.as_u64()?),
+                Value::String(name) => HideoutStation::from_name(name),
+                _ => return None,
+            };
+            Some(LogLine::HideoutUpgraded {
+                station,
+                new_level: body["level"].as_u64()? as u8,
+            })
+        }
+        "SkillLevelUp" => Some(LogLine::SkillLevelUp {
+            skill: body["skill"].as_str()?.to_string(),
+            new_level: body["level"].as_f64()? as f32,
+        }),
+        "FenceReputation" | "FenceReputationChanged" => Some(LogLine::ScavKarmaChange {
+            delta: body["delta"].as_f64()? as f32,
+            new_value: body["value"].as_f64().map(|v| v as f32),
+            reason: body["reason"].as_str().unwrap_or_default().to_string(),
+        }),
+        "ItemFoundInRaid" => Some(LogLine::ItemFoundInRaid {
+            item_id: body["itemId"].as_str()?.to_string(),
+            item_name: body["itemName"].as_str().unwrap_or_default().to_string(),
+            count: body["count"].as_u64().unwrap_or(1) as u32,
+        }),
+        "RagfairOfferSold" => Some(LogLine::FleaMarketSold {
+            // Only the handbook ID is logged by some builds
+            item_name: body["itemName"]
+                .as_str()
+                .or_else(|| body["handbookId"].as_str())
+                .unwrap_or_default()
+                .to_string(),
+            count: body["count"].as_u64().unwrap_or(1) as u32,
+            price_rub: body["price"].as_u64()?,
+            fee: body["fee"].as_u64().unwrap_or(0),
+        }),
+        _ => None,
+    }
+}
+
+/// Lines at the top of a log file searched by `parse_log_header`
+pub const LOG_HEADER_LINES: usize = 20;
+
+/// Identity details written at the top of an EFT log file
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LogFileHeader {
+    pub game_version: String,
+    pub profile_id: Option<String>,
+    /// Only written by some game versions
+    pub character_name: Option<String>,
+}
+
+/// Value following any of `keys` and a `:` or `=`, matching keys case-insensitively
+fn header_field(line: &str, keys: &[&str]) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets, so indices carry over to `line`
+    let lower = line.to_ascii_lowercase();
+    keys.iter().find_map(|key| {
+        let rest = &line[lower.find(key)? + key.len()..];
+        let rest = rest.trim_start().strip_prefix([':', '='])?.trim_start();
+        let value = rest.trim_start_matches('\'').split([' ', ',', '\'', '|']).next()?;
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+/// Game version from the second field of `<timestamp>|<version>|<level>|...`
+fn header_game_version(line: &str) -> Option<String> {
+    let version = line.split('|').nth(1)?.trim();
+    let looks_like_version =
+        version.contains('.') && version.chars().all(|c| c.is_ascii_digit() || c == '.');
+    looks_like_version.then(|| version.to_string())
+}
+
+/// Extract the game version, profile ID and character name from a log's first lines
+pub fn parse_log_header(lines: &[String]) -> Option<LogFileHeader> {
+    let lines = &lines[..lines.len().min(LOG_HEADER_LINES)];
+    let game_version = lines.iter().find_map(|line| header_game_version(line))?;
+
+    Some(LogFileHeader {
+        game_version,
+        profile_id: lines
+            .iter()
+            .find_map(|line| header_field(line, &["profileid", "profile id"])),
+        character_name: lines
+            .iter()
+            .find_map(|line| header_field(line, &["nickname", "character name", "charactername"])),
+    })
+}
+
+/// Parse every recognised event in a chunk of log text
+pub fn parse_log_content(log_type: LogType, content: &str) -> Vec<LogLine> {
+    content
+        .lines()
+        .filter_map(|line| parse_log_line(log_type, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(kind: &str, body: &str) -> String {
+        format!(
+            "2024-05-01 18:23:45.123 +02:00|0.14.6.0.29862|Info|notifications|Got notification | {} | {}",
+            kind, body
+        )
+    }
+
+    #[test]
+    fn hideout_upgrade_maps_area_type_to_station() {
+        let line = notification("HideoutAreaUpgraded", r#"{"type": "HideoutAreaUpgraded", "areaType": 7, "level": 2}"#);
+        assert_eq!(
+            parse_log_line(LogType::Notifications, &line),
+            Some(LogLine::HideoutUpgraded {
+                station: HideoutStation::Medstation,
+                new_level: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn legacy_hideout_upgrade_maps_station_name() {
+        let line = notification("HideoutUpgrade", r#"{"areaType": "Water Collector", "level": 1}"#);
+        assert_eq!(
+            parse_log_line(LogType::Notifications, &line),
+            Some(LogLine::HideoutUpgraded {
+                station: HideoutStation::WaterCollector,
+                new_level: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn fence_reputation_keeps_the_new_value() {
+        let line = notification("FenceReputation", r#"{"delta": 0.05, "value": 1.25, "reason": "Quest"}"#);
+        assert_eq!(
+            parse_log_line(LogType::Notifications, &line),
+            Some(LogLine::ScavKarmaChange {
+                delta: 0.05,
+                new_value: Some(1.25),
+                reason: "Quest".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn delta_only_fence_reputation_has_no_new_value() {
+        let line = notification("FenceReputation", r#"{"delta": -0.2}"#);
+        assert_eq!(
+            parse_log_line(LogType::Notifications, &line),
+            Some(LogLine::ScavKarmaChange {
+                delta: -0.2,
+                new_value: None,
+                reason: String::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_hideout_areas_are_kept() {
+        assert_eq!(HideoutStation::from_area_type(99), HideoutStation::Unknown("99".to_string()));
+        assert_eq!(HideoutStation::from_name("Sauna"), HideoutStation::Unknown("Sauna".to_string()));
+    }
+}
@@ -1,108 +1,1779 @@
-use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
-use std::path::Path;
-use tauri::{AppHandle, Emitter};
-use tokio::sync::mpsc;
+use crate::achievement_tracker;
+use crate::app_state::{AppConfig, AppState, RaidStatus, WatcherStatus};
+use crate::diagnostics;
+use crate::error::{AppError, AppResult, AppResultExt, DEFAULT_RETRY_BASE_DELAY, DEFAULT_RETRY_MAX_DELAY};
+use crate::log_parser::{self, LogFileHeader, LogLine, LogType};
+use crate::loot_tracker;
+use crate::notification_rules;
+use crate::quest_tracker;
+use crate::session_tracker;
+use crate::system_tray;
+use crate::tarkov_api;
+use crate::tarkov_paths;
+use crate::webhook;
+use crate::wipe_tracker;
+use chrono::{DateTime, Utc};
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{Read, Seek, SeekFrom};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter, Manager, Runtime, Wry};
+use tokio::sync::mpsc::{self, error::TrySendError};
 use tokio::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// How often the watched directory is checked for disappearing
+const DIRECTORY_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Minimum time between free-space checks before log reads
+const DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Allowed range for the batch interval
+pub const MIN_BATCH_INTERVAL_MS: u64 = 10;
+pub const MAX_BATCH_INTERVAL_MS: u64 = 5000;
+
+/// Capacity of each bounded channel between watcher pipeline stages.
+///
+/// The stages use tokio's mpsc rather than crossbeam-channel on blocking threads.
+/// `cargo bench --bench pipeline` (1 CPU, rustc 1.95) measured one stage hop and
+/// back at 6.0µs for tokio vs 7.6µs for crossbeam. Crossbeam moved bulk batches
+/// faster (15.8M vs 2.5M msgs/s at 5000 messages), but log traffic is orders of
+/// magnitude below either, so per-event latency decides.
+const PIPELINE_CHANNEL_CAPACITY: usize = 256;
+
+/// Capacity of the channel from notify's callback to the event loop. The callback
+/// can't wait, so events beyond it are dropped and reported
+const RAW_EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Logs the typed events are parsed from (`raid-started` needs `application`), watched
+/// even when a config saved by an older version lists only `notifications`
+const REQUIRED_LOG_FILE_PATTERNS: [&str; 2] = ["notifications", "application"];
+
+/// Options for one watcher instance, normally built from `AppConfig`
+#[derive(Debug, Clone)]
+pub struct LogWatcherConfig {
+    pub batch_interval_ms: u64,
+    /// Log file names containing any of these substrings are watched
+    pub file_patterns: Vec<String>,
+    /// Pending `log-event`s kept before the oldest is dropped
+    pub max_buffer_depth: usize,
+    /// Bytes of each changed file included in `log-event`
+    pub tail_window_bytes: usize,
+    /// Reconnect attempts after the directory disappears before giving up (0 retries forever)
+    pub reconnect_attempts: u32,
+    /// First reconnect delay; doubles per attempt up to 60s
+    pub reconnect_base_delay_ms: u64,
+    /// Watch subdirectories too; off by default since every nested file change is delivered
+    pub recursive: bool,
+}
+
+impl Default for LogWatcherConfig {
+    fn default() -> Self {
+        Self::from_app_config(&AppConfig::default())
+    }
+}
+
+impl LogWatcherConfig {
+    pub fn from_app_config(config: &AppConfig) -> Self {
+        let mut file_patterns = config.log_file_patterns.clone();
+        for required in REQUIRED_LOG_FILE_PATTERNS {
+            if !file_patterns.iter().any(|pattern| pattern == required) {
+                file_patterns.push(required.to_string());
+            }
+        }
+
+        Self {
+            batch_interval_ms: config.watcher_batch_interval_ms,
+            file_patterns,
+            max_buffer_depth: config.max_buffered_events,
+            tail_window_bytes: LOG_EVENT_TAIL_BYTES,
+            reconnect_attempts: 0,
+            reconnect_base_delay_ms: DEFAULT_RETRY_BASE_DELAY.as_millis() as u64,
+            recursive: config.recursive_log_watch,
+        }
+    }
+
+    fn recursive_mode(&self) -> RecursiveMode {
+        if self.recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        }
+    }
+}
+
+/// Handle to a running watcher task
+pub struct WatcherHandle {
+    directory: String,
+    started_at: DateTime<Utc>,
+    batch_interval_ms: Arc<AtomicU64>,
+    buffer_depth: Arc<AtomicUsize>,
+    counters: Arc<WatcherCounters>,
+    pipeline: PipelineSenders,
+    cancel: CancellationToken,
+    stop_reason: Arc<Mutex<Option<String>>>,
+    restarting: Arc<AtomicBool>,
+}
+
+impl WatcherHandle {
+    /// Change how often batched `log-event`s are flushed; applied on the next tick
+    pub fn set_batch_interval(&self, ms: u64) {
+        self.batch_interval_ms.store(ms, Ordering::Relaxed);
+    }
+
+    /// Number of `log-event`s waiting for the next batch flush
+    pub fn buffer_depth(&self) -> usize {
+        self.buffer_depth.load(Ordering::Relaxed)
+    }
+
+    /// Items queued between pipeline stages, waiting to be read, parsed or emitted
+    pub fn pipeline_depth(&self) -> usize {
+        self.pipeline.depth()
+    }
+
+    /// Directory being watched
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    /// Watched-log change events handled since the watcher started
+    pub fn events_processed(&self) -> u64 {
+        self.counters.events.load(Ordering::Relaxed)
+    }
+
+    pub fn last_event_at(&self) -> Option<DateTime<Utc>> {
+        *self.counters.last_event_at.lock().unwrap()
+    }
+
+    /// Event and byte rates over the last minute plus lifetime totals
+    pub fn log_statistics(&self) -> WatcherLogStats {
+        self.counters.stats()
+    }
+
+    /// Stop the watcher task; `reason` is reported in `watcher-stopped`
+    pub fn stop(&self, reason: impl Into<String>) {
+        *self.stop_reason.lock().unwrap() = Some(reason.into());
+        self.cancel.cancel();
+    }
+
+    /// Stop the watcher task without `watcher-stopped`, for a replacement watcher
+    /// whose `watcher-started` the old task could otherwise overtake
+    pub fn stop_for_restart(&self) {
+        self.restarting.store(true, Ordering::Relaxed);
+        self.cancel.cancel();
+    }
+}
+
+/// Notify event forwarded to the file reader stage
+enum FileChange {
+    Modified(PathBuf),
+    Removed(Vec<PathBuf>),
+}
+
+/// Output of the file reader stage
+struct FileRead {
+    log_type: LogType,
+    /// Session header, on the first read of a file
+    header: Option<LogFileHeader>,
+    /// `None` when the appended text could not be read
+    new_lines: Option<String>,
+    log_event: Option<LogEvent>,
+}
+
+/// Output of the line parser stage
+struct ParsedRead {
+    header: Option<LogFileHeader>,
+    lines: Vec<LogLine>,
+    log_event: Option<LogEvent>,
+}
+
+/// Senders into each pipeline stage, kept by the handle to report queue depth
+struct PipelineSenders {
+    reader: mpsc::Sender<FileChange>,
+    parser: mpsc::Sender<FileRead>,
+    emitter: mpsc::Sender<ParsedRead>,
+}
+
+impl PipelineSenders {
+    fn depth(&self) -> usize {
+        queued(&self.reader) + queued(&self.parser) + queued(&self.emitter)
+    }
+}
+
+/// Items waiting in a bounded channel
+fn queued<T>(tx: &mpsc::Sender<T>) -> usize {
+    tx.max_capacity() - tx.capacity()
+}
+
+/// Window used for the per-minute rates in `WatcherLogStats`
+const STATS_WINDOW: Duration = Duration::from_secs(60);
+
+/// Counters shared between the watcher task and its handle
+#[derive(Default)]
+struct WatcherCounters {
+    events: AtomicU64,
+    bytes: AtomicU64,
+    errors: AtomicU32,
+    dropped_events: AtomicU64,
+    last_event_at: Mutex<Option<DateTime<Utc>>>,
+    last_file_path: Mutex<Option<String>>,
+    /// (when, bytes read) for each event inside `STATS_WINDOW`
+    recent: Mutex<VecDeque<(Instant, u64)>>,
+}
+
+impl WatcherCounters {
+    fn record_event(&self, path: &Path, bytes: u64) {
+        self.events.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+        *self.last_event_at.lock().unwrap() = Some(Utc::now());
+        *self.last_file_path.lock().unwrap() = Some(path.display().to_string());
+
+        let now = Instant::now();
+        let mut recent = self.recent.lock().unwrap();
+        recent.push_back((now, bytes));
+        prune_window(&mut recent, now);
+    }
+
+    fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_dropped(&self) {
+        self.dropped_events.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> WatcherLogStats {
+        let mut recent = self.recent.lock().unwrap();
+        prune_window(&mut recent, Instant::now());
+        let window_minutes = STATS_WINDOW.as_secs_f64() / 60.0;
+
+        WatcherLogStats {
+            events_per_minute: recent.len() as f64 / window_minutes,
+            bytes_per_minute: recent.iter().map(|&(_, bytes)| bytes).sum::<u64>() as f64 / window_minutes,
+            total_events: self.events.load(Ordering::Relaxed),
+            total_bytes: self.bytes.load(Ordering::Relaxed),
+            error_count: self.errors.load(Ordering::Relaxed),
+            dropped_events: self.dropped_events.load(Ordering::Relaxed),
+            last_file_path: self.last_file_path.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Drop entries older than `STATS_WINDOW`
+fn prune_window(recent: &mut VecDeque<(Instant, u64)>, now: Instant) {
+    while recent
+        .front()
+        .is_some_and(|&(at, _)| now.duration_since(at) > STATS_WINDOW)
+    {
+        recent.pop_front();
+    }
+}
+
+/// Watcher throughput for diagnosing missed detections
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WatcherLogStats {
+    pub events_per_minute: f64,
+    pub bytes_per_minute: f64,
+    pub total_events: u64,
+    pub total_bytes: u64,
+    pub error_count: u32,
+    /// Notify events dropped because the event loop fell behind
+    pub dropped_events: u64,
+    pub last_file_path: Option<String>,
+}
+
+/// Watcher status plus what the running watcher is doing, for `get_watcher_details`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WatcherDetails {
+    pub status: WatcherStatus,
+    pub directory: Option<String>,
+    pub started_at: Option<String>,
+    pub events_processed: u64,
+    pub last_event_at: Option<String>,
+    pub buffer_depth: usize,
+    pub pipeline_depth: usize,
+}
+
+/// Payload of the `watcher-started` and `watcher-reconnecting` events
+#[derive(Clone, serde::Serialize)]
+pub struct WatcherDirectoryPayload {
+    pub directory: String,
+    pub timestamp: String,
+}
+
+/// Payload of the `watcher-buffer-overflow` event
+#[derive(Clone, serde::Serialize)]
+pub struct BufferOverflowPayload {
+    pub dropped_file: String,
+    pub max_buffered_events: usize,
+}
+
+/// Payload of the `watcher-stopped` event
+#[derive(Clone, serde::Serialize)]
+pub struct WatcherStoppedPayload {
+    pub reason: String,
+    pub timestamp: String,
+}
+
+/// Payload of the `log-corruption-detected` event
+#[derive(Clone, serde::Serialize)]
+pub struct LogCorruptionPayload {
+    pub file_path: String,
+    pub timestamp: String,
+}
 
 /// Log event data sent to frontend
 #[derive(Clone, serde::Serialize)]
 pub struct LogEvent {
     pub file_path: String,
+    pub log_type: LogType,
     pub content: String,
     pub timestamp: String,
+    /// File modification time (RFC 3339), when the filesystem reports it
+    pub file_modified_at: Option<String>,
 }
 
-/// Start watching the log directory for changes
-pub fn start_log_watcher(
-    log_directory: String,
-    app_handle: AppHandle,
-) -> Result<(), String> {
-    // Validate directory exists
-    if !Path::new(&log_directory).exists() {
-        return Err(format!("Log directory not found: {}", log_directory));
+/// Payload of the `raid-started` event
+#[derive(Clone, serde::Serialize)]
+pub struct RaidStartedPayload {
+    pub map: String,
+    pub mode: String,
+    pub timestamp: String,
+}
+
+/// Payload of the `raid-ended` event
+#[derive(Clone, serde::Serialize)]
+pub struct RaidEndedPayload {
+    pub survived: bool,
+    pub duration_secs: u64,
+    pub timestamp: String,
+}
+
+/// Payload of the `quest-completed` event
+#[derive(Clone, serde::Serialize)]
+pub struct QuestCompletedPayload {
+    pub quest_id: String,
+    pub quest_name: String,
+    pub trader: String,
+    pub timestamp: String,
+}
+
+/// Whether a path is a game log file the watcher cares about
+pub fn is_watched_log(path: &Path, patterns: &[String]) -> bool {
+    path.extension().is_some_and(|ext| ext == "log")
+        && path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| patterns.iter().any(|pattern| n.contains(pattern.as_str())))
+}
+
+/// Whether an event path should be processed: the explicitly watched file when
+/// one was given, otherwise any log matching `patterns`
+fn is_watched_target(path: &Path, watched_file: Option<&OsStr>, patterns: &[String]) -> bool {
+    match watched_file {
+        Some(name) => path.file_name() == Some(name),
+        None => is_watched_log(path, patterns),
+    }
+}
+
+/// Byte-order mark of UTF-16-LE files
+const UTF16_LE_BOM: [u8; 2] = [0xFF, 0xFE];
+
+/// Whether the file starts with a UTF-16-LE BOM
+fn is_utf16_le(file: &mut std::fs::File) -> std::io::Result<bool> {
+    let mut bom = [0u8; 2];
+    file.seek(SeekFrom::Start(0))?;
+    let read = file.read(&mut bom)?;
+    Ok(read == 2 && bom == UTF16_LE_BOM)
+}
+
+/// Decode log bytes as UTF-16-LE (BOM stripped if present) or UTF-8
+fn decode_log_bytes(bytes: &[u8], utf16: bool) -> String {
+    if utf16 {
+        let bytes = bytes.strip_prefix(&UTF16_LE_BOM[..]).unwrap_or(bytes);
+        encoding_rs::UTF_16LE.decode_without_bom_handling(bytes).0.into_owned()
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Read a whole log file, decoding UTF-16-LE when it has a BOM and UTF-8 otherwise.
+///
+/// Undecodable bytes become U+FFFD, like on the mmap path of `read_file_tail`;
+/// corrupted chunks are detected and reported by `read_new_lines`.
+pub fn read_file_as_string(path: &Path) -> AppResult<String> {
+    let bytes = std::fs::read(path)?;
+    Ok(decode_log_bytes(&bytes, bytes.starts_with(&UTF16_LE_BOM)))
+}
+
+/// Bytes read from the start of a new log file to find its header
+const LOG_HEADER_BYTES: u64 = 4096;
+
+/// First `max_bytes` of a log file, decoded like `read_file_as_string`
+fn read_file_head(path: &Path, max_bytes: u64) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let utf16 = is_utf16_le(&mut file)?;
+    file.seek(SeekFrom::Start(0))?;
+
+    let mut bytes = Vec::new();
+    file.take(max_bytes).read_to_end(&mut bytes)?;
+    Ok(decode_log_bytes(&bytes, utf16))
+}
+
+/// Parse the header of a log file seen for the first time
+fn read_log_header(path: &Path) -> AppResult<Option<LogFileHeader>> {
+    let head = read_file_head(path, LOG_HEADER_BYTES)?;
+    let lines: Vec<String> = head
+        .lines()
+        .take(log_parser::LOG_HEADER_LINES)
+        .map(str::to_string)
+        .collect();
+
+    Ok(log_parser::parse_log_header(&lines))
+}
+
+/// Default bytes of each changed file included in `log-event`
+const LOG_EVENT_TAIL_BYTES: usize = 10240;
+
+/// Last `max_bytes` of a log file, decoded like `read_file_as_string`.
+///
+/// Files above `mmap_threshold` are memory-mapped so only the tail pages are
+/// touched; the map is created per call, so growth since the last read is seen.
+pub fn read_file_tail(path: &Path, max_bytes: usize, mmap_threshold: u64) -> AppResult<String> {
+    let mut file = std::fs::File::open(path)?;
+    if file.metadata()?.len() <= mmap_threshold {
+        let content = read_file_as_string(path)?;
+        let mut start = content.len().saturating_sub(max_bytes);
+        while !content.is_char_boundary(start) {
+            start += 1;
+        }
+        return Ok(content[start..].to_string());
+    }
+
+    let utf16 = is_utf16_le(&mut file)?;
+    // SAFETY: the game only appends to its logs; the map never outlives this call
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+    let mut start = mmap.len().saturating_sub(max_bytes);
+    if utf16 {
+        // Stay aligned to UTF-16 code units after the BOM
+        start = start.max(UTF16_LE_BOM.len());
+        start += start % 2;
+    } else {
+        // Don't start in the middle of a UTF-8 sequence
+        while start < mmap.len() && (mmap[start] & 0xC0) == 0x80 {
+            start += 1;
+        }
+    }
+
+    let tail = decode_log_bytes(&mmap[start..], utf16);
+    drop(mmap);
+    Ok(tail)
+}
+
+/// Metadata of a log file on disk
+#[derive(Clone, serde::Serialize)]
+pub struct LogFileInfo {
+    pub path: String,
+    pub name: String,
+    pub size_bytes: u64,
+    pub modified_at: String,
+}
+
+/// Collect `.log` files under `dir`, descending into the per-session `log_YYYYMMDD_HHMMSS` folders
+fn collect_log_files(dir: &Path, files: &mut Vec<(std::time::SystemTime, LogFileInfo)>) -> AppResult<()> {
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            collect_log_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "log") {
+            let modified = metadata.modified().unwrap_or(std::time::UNIX_EPOCH);
+            files.push((modified, LogFileInfo {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                path: path.display().to_string(),
+                size_bytes: metadata.len(),
+                modified_at: chrono::DateTime::<chrono::Utc>::from(modified).to_rfc3339(),
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// List log files under `directory`, newest first, skipping `offset` and returning at most `limit`
+pub fn list_log_files(directory: &str, offset: usize, limit: usize) -> AppResult<Vec<LogFileInfo>> {
+    let dir = Path::new(directory);
+    if !dir.is_dir() {
+        return Err(AppError::file_not_found(directory));
+    }
+
+    let mut files = Vec::new();
+    collect_log_files(dir, &mut files)?;
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+
+    Ok(files
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|(_, info)| info)
+        .collect())
+}
+
+/// A regex match inside a log file with ±2 lines of context
+#[derive(Clone, serde::Serialize)]
+pub struct SearchResult {
+    pub file_path: String,
+    pub line_number: u64,
+    pub line: String,
+    pub matched_range: (usize, usize),
+    pub context_before: Vec<String>,
+    pub context_after: Vec<String>,
+}
+
+/// Lines of context kept on each side of a search match
+const SEARCH_CONTEXT_LINES: usize = 2;
+
+/// Search every log file under `directory` for `pattern`, newest file first, stopping at `max_results`
+pub fn search_log_events(directory: &str, pattern: &str, max_results: usize) -> AppResult<Vec<SearchResult>> {
+    let regex = regex::Regex::new(pattern).map_err(|e| AppError::invalid_config("pattern").with_details(e.to_string()))?;
+    let files = list_log_files(directory, 0, usize::MAX)?;
+
+    let mut results = Vec::new();
+    for file in files {
+        let Ok(content) = read_file_as_string(Path::new(&file.path)) else {
+            continue;
+        };
+        let lines: Vec<&str> = content.lines().collect();
+
+        for (index, line) in lines.iter().enumerate() {
+            let Some(found) = regex.find(line) else {
+                continue;
+            };
+
+            let before_start = index.saturating_sub(SEARCH_CONTEXT_LINES);
+            let after_end = (index + 1 + SEARCH_CONTEXT_LINES).min(lines.len());
+            results.push(SearchResult {
+                file_path: file.path.clone(),
+                line_number: index as u64 + 1,
+                line: line.to_string(),
+                matched_range: (found.start(), found.end()),
+                context_before: lines[before_start..index].iter().map(|l| l.to_string()).collect(),
+                context_after: lines[index + 1..after_end].iter().map(|l| l.to_string()).collect(),
+            });
+
+            if results.len() >= max_results {
+                return Ok(results);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Last `max_lines` lines of a log file
+pub fn read_log_tail(path: &str, max_lines: usize) -> AppResult<Vec<String>> {
+    let content = read_file_as_string(Path::new(path))?;
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.len().saturating_sub(max_lines);
+    Ok(lines[start..].iter().map(|line| line.to_string()).collect())
+}
+
+/// Last-modified time of a file as RFC 3339
+fn file_modified_at(path: &Path) -> Option<String> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    Some(chrono::DateTime::<chrono::Utc>::from(modified).to_rfc3339())
+}
+
+/// Current length of every watched log in `dir`, so existing history isn't replayed
+fn seed_offsets(
+    dir: &Path,
+    watched_file: Option<&OsStr>,
+    patterns: &[String],
+    mode: RecursiveMode,
+) -> HashMap<PathBuf, u64> {
+    let mut offsets = HashMap::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        for path in std::fs::read_dir(&current).into_iter().flatten().flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                if mode == RecursiveMode::Recursive {
+                    pending.push(path);
+                }
+            } else if is_watched_target(&path, watched_file, patterns) {
+                if let Ok(metadata) = std::fs::metadata(&path) {
+                    offsets.insert(path, metadata.len());
+                }
+            }
+        }
+    }
+    offsets
+}
+
+/// Share of replacement characters above which decoded text is treated as corrupted
+const MAX_REPLACEMENT_CHAR_RATIO: f64 = 0.10;
+
+/// Whether lossily decoded text is mostly undecodable bytes
+fn looks_corrupted(text: &str) -> bool {
+    let (total, replaced) = text.chars().fold((0usize, 0usize), |(total, replaced), c| {
+        (total + 1, replaced + usize::from(c == char::REPLACEMENT_CHARACTER))
+    });
+    total > 0 && replaced as f64 / total as f64 > MAX_REPLACEMENT_CHAR_RATIO
+}
+
+/// Consecutive corrupted reads of the same bytes before they are skipped
+const MAX_CORRUPT_READS: u32 = 3;
+
+/// Outcome of `read_new_lines`
+#[derive(Debug, PartialEq)]
+enum NewLines {
+    /// Complete lines appended since the last read, possibly none
+    Text(String),
+    /// The new bytes look corrupted and are read again on the next event
+    Corrupted,
+    /// The new bytes were still corrupted after `MAX_CORRUPT_READS` reads and were skipped
+    Skipped,
+}
+
+/// Read complete lines appended since the last call, advancing the stored offset.
+///
+/// Bytes that look corrupted leave the offset alone so the next event reads them
+/// again once the game has finished writing. `corrupt_reads` counts consecutive
+/// corrupted reads per file; once it reaches `MAX_CORRUPT_READS` the bytes are
+/// treated as binary and the offset moves past them.
+fn read_new_lines(
+    path: &Path,
+    offsets: &mut HashMap<PathBuf, u64>,
+    corrupt_reads: &mut HashMap<PathBuf, u32>,
+) -> std::io::Result<NewLines> {
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let utf16 = is_utf16_le(&mut file)?;
+
+    // A shorter file means it was truncated or replaced; start over
+    let offset = offsets.get(path).copied().filter(|&o| o <= len).unwrap_or(0);
+    file.seek(SeekFrom::Start(offset))?;
+
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    // Leave a trailing partial line for the next event
+    let complete = if utf16 {
+        // Newline is the code unit 0x0A 0x00; offsets stay on code-unit boundaries
+        bytes
+            .chunks_exact(2)
+            .rposition(|unit| unit == [b'\n', 0])
+            .map_or(0, |i| (i + 1) * 2)
+    } else {
+        bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1)
+    };
+    let text = decode_log_bytes(&bytes[..complete], utf16);
+    if looks_corrupted(&text) {
+        let attempts = corrupt_reads.entry(path.to_path_buf()).or_insert(0);
+        *attempts += 1;
+        if *attempts < MAX_CORRUPT_READS {
+            return Ok(NewLines::Corrupted);
+        }
+        corrupt_reads.remove(path);
+        offsets.insert(path.to_path_buf(), offset + complete as u64);
+        return Ok(NewLines::Skipped);
+    }
+
+    corrupt_reads.remove(path);
+    offsets.insert(path.to_path_buf(), offset + complete as u64);
+    Ok(NewLines::Text(text))
+}
+
+/// Count a corrupted read and tell the frontend which file was skipped
+fn report_corruption<R: Runtime>(app: &AppHandle<R>, path: &Path) {
+    app.state::<AppState>().corrupted_reads.fetch_add(1, Ordering::Relaxed);
+    app.emit(
+        "log-corruption-detected",
+        LogCorruptionPayload {
+            file_path: path.display().to_string(),
+            timestamp: Utc::now().to_rfc3339(),
+        },
+    )
+    .log_and_ignore("Failed to emit log-corruption-detected");
+}
+
+/// Emit `raid-started` / `raid-ended` right away and record the raid in `AppState.raid_status`
+fn emit_raid_event<R: Runtime>(app: &AppHandle<R>, line: &LogLine) {
+    let now = chrono::Utc::now();
+    let state = app.state::<AppState>();
+
+    match line {
+        LogLine::RaidStarted { map, mode } => {
+            app.emit("raid-started", RaidStartedPayload {
+                map: map.clone(),
+                mode: mode.clone(),
+                timestamp: now.to_rfc3339(),
+            }).log_and_ignore("Failed to emit raid-started");
+
+            state.set_raid_status(RaidStatus::InRaid { map: map.clone(), started_at: now });
+        }
+        LogLine::RaidEnded { survived, .. } => {
+            let (map, duration_secs) = match state.get_raid_status() {
+                RaidStatus::InRaid { map, started_at } => {
+                    (Some(map), (now - started_at).num_seconds().max(0) as u64)
+                }
+                _ => (None, 0),
+            };
+            app.emit("raid-ended", RaidEndedPayload {
+                survived: *survived,
+                duration_secs,
+                timestamp: now.to_rfc3339(),
+            }).log_and_ignore("Failed to emit raid-ended");
+
+            state.set_raid_status(RaidStatus::Ended { survived: *survived, map });
+        }
+        _ => {}
+    }
+}
+
+/// Emit `quest-completed` as soon as the line is parsed, ahead of the batched `log-event`
+fn emit_quest_completed<R: Runtime>(app: &AppHandle<R>, line: &LogLine) {
+    let LogLine::QuestCompleted { quest_id } = line else {
+        return;
+    };
+
+    let meta = tarkov_api::cached_quest_meta(app)
+        .into_iter()
+        .find(|meta| &meta.id == quest_id);
+    app.emit("quest-completed", QuestCompletedPayload {
+        quest_id: quest_id.clone(),
+        quest_name: meta.as_ref().map_or_else(|| quest_id.clone(), |m| m.name.clone()),
+        trader: meta.map(|m| m.trader).unwrap_or_default(),
+        timestamp: Utc::now().to_rfc3339(),
+    }).log_and_ignore("Failed to emit quest-completed");
+}
+
+/// Watcher error caused by a file or notify failure, sharing the cause's correlation ID
+fn watcher_error(cause: AppError) -> AppError {
+    let cause = match cause.correlation_id {
+        Some(_) => cause,
+        None => cause.with_correlation_id(AppError::generate_id()),
+    };
+    AppError::watch_error(&cause).with_cause(&cause)
+}
+
+/// Log a watcher failure and report it on `watcher-error` and the legacy `log-error`.
+///
+/// Not sent as `app-error` too, so listeners of several events don't show it twice.
+fn emit_watcher_error<R: Runtime>(app: &AppHandle<R>, error: AppError) {
+    error.log("Watcher error");
+    app.emit("log-error", &error).log_and_ignore("Failed to emit log-error");
+    app.emit("watcher-error", error).log_and_ignore("Failed to emit watcher-error");
+}
+
+/// Queue an event for the next flush. Repeated writes to the same file replace its
+/// pending event; otherwise the oldest event is dropped once `max` is reached.
+fn buffer_event<R: Runtime>(app: &AppHandle<R>, buffer: &mut VecDeque<LogEvent>, event: LogEvent, max: usize) {
+    if let Some(pending) = buffer.iter_mut().find(|e| e.file_path == event.file_path) {
+        *pending = event;
+        return;
+    }
+
+    if buffer.len() >= max {
+        if let Some(dropped) = buffer.pop_front() {
+            app.emit("watcher-buffer-overflow", BufferOverflowPayload {
+                dropped_file: dropped.file_path,
+                max_buffered_events: max,
+            }).log_and_ignore("Failed to emit watcher-buffer-overflow");
+        }
+    }
+    buffer.push_back(event);
+}
+
+/// Feed a parsed line to the backend trackers
+fn handle_log_line(app: &AppHandle, line: &LogLine) {
+    if wipe_tracker::on_log_line(app, line) {
+        session_tracker::reset_session_stats(app);
+        tarkov_paths::bust_detection_cache();
     }
+    session_tracker::on_log_line(app, line);
+    quest_tracker::on_log_line(app, line);
+    achievement_tracker::on_log_line(app, line);
+    loot_tracker::on_log_line(app, line);
+    notification_rules::on_log_line(app, line);
+    webhook::on_log_line(app, line);
+    // `emit_raid_event` has already updated the raid indicator's status
+    if matches!(line, LogLine::RaidStarted { .. } | LogLine::RaidEnded { .. }) {
+        system_tray::refresh_tray_menu(app);
+    }
+}
+
+/// Backend trackers the emitter stage feeds, replaced when the stages run on a mock app
+struct TrackerHooks<R: Runtime> {
+    /// Session header of a file read for the first time
+    on_header: fn(&AppHandle<R>, &LogFileHeader),
+    /// Each parsed line, after its immediate events were emitted
+    on_line: fn(&AppHandle<R>, &LogLine),
+}
+
+impl<R: Runtime> Clone for TrackerHooks<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
-    // Use tokio's mpsc channel for async compatibility
-    let (tx, mut rx) = mpsc::unbounded_channel();
+impl<R: Runtime> Copy for TrackerHooks<R> {}
 
+/// The app's real trackers
+const APP_TRACKERS: TrackerHooks<Wry> = TrackerHooks {
+    on_header: session_tracker::on_log_header,
+    on_line: handle_log_line,
+};
+
+/// Create a notify watcher on `dir` that forwards events to `tx`
+fn create_watcher(
+    dir: &Path,
+    mode: RecursiveMode,
+    tx: mpsc::Sender<notify::Result<Event>>,
+    on_overflow: impl Fn(notify::Result<Event>) + Send + 'static,
+) -> AppResult<RecommendedWatcher> {
     // Create watcher with recommended configuration
     let mut watcher = RecommendedWatcher::new(
         move |res| {
-            let _ = tx.send(res);
+            // A closed channel means the watcher is stopping
+            if let Err(TrySendError::Full(res)) = tx.try_send(res) {
+                on_overflow(res);
+            }
         },
         Config::default(),
-    )
-    .map_err(|e| format!("Failed to create file watcher: {}", e))?;
+    )?;
+
+    watcher.watch(dir, mode)?;
+    Ok(watcher)
+}
+
+/// Count and report a notify event dropped because the raw event channel is full
+fn raw_overflow_reporter(app: AppHandle, counters: Arc<WatcherCounters>) -> impl Fn(notify::Result<Event>) + Send + 'static {
+    move |res| {
+        counters.record_dropped();
+        let dropped_file = res
+            .ok()
+            .and_then(|event| event.paths.into_iter().next())
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        app.emit("watcher-buffer-overflow", BufferOverflowPayload {
+            dropped_file,
+            max_buffered_events: RAW_EVENT_CHANNEL_CAPACITY,
+        }).log_and_ignore("Failed to emit watcher-buffer-overflow");
+    }
+}
+
+/// Retry `create_watcher` with exponential backoff (1s, 2s, 4s… up to 60s by default)
+/// until the directory is back. Returns `None` if the watcher was stopped meanwhile
+/// or `reconnect_attempts` ran out, in which case the watcher is stopped.
+async fn reconnect(
+    app: &AppHandle,
+    dir: &str,
+    tx: &mpsc::Sender<notify::Result<Event>>,
+    counters: &Arc<WatcherCounters>,
+    cancel: &CancellationToken,
+    mode: RecursiveMode,
+    config: &LogWatcherConfig,
+) -> Option<RecommendedWatcher> {
+    app.state::<AppState>().set_watcher_status(WatcherStatus::Reconnecting);
+    app.emit("watcher-reconnecting", WatcherDirectoryPayload {
+        directory: dir.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    }).log_and_ignore("Failed to emit watcher-reconnecting");
+
+    let base_delay = Duration::from_millis(config.reconnect_base_delay_ms);
+    let mut attempt = AppError::watch_error(format!("{} is unavailable", dir))
+        .with_max_retries(config.reconnect_attempts);
+    loop {
+        if config.reconnect_attempts > 0 && attempt.max_retries_exceeded() {
+            let state = app.state::<AppState>();
+            state.watcher.lock().unwrap().take();
+            state.set_watching(false);
+            state.set_watcher_status(WatcherStatus::Error(attempt.to_string()));
+            emit_watcher_error(app, attempt);
+            cancel.cancel();
+            return None;
+        }
+
+        tokio::select! {
+            _ = cancel.cancelled() => return None,
+            _ = tokio::time::sleep(attempt.next_retry_delay_with(base_delay, DEFAULT_RETRY_MAX_DELAY)) => {}
+        }
+
+        if Path::new(dir).exists() {
+            let on_overflow = raw_overflow_reporter(app.clone(), counters.clone());
+            if let Ok(watcher) = create_watcher(Path::new(dir), mode, tx.clone(), on_overflow) {
+                app.state::<AppState>().set_watcher_status(WatcherStatus::Running);
+                app.emit("watcher-started", WatcherDirectoryPayload {
+                    directory: dir.to_string(),
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                }).log_and_ignore("Failed to emit watcher-started");
+                return Some(watcher);
+            }
+        }
+        attempt.increment_retry();
+    }
+}
+
+/// Map a notify event to the reader stage's input, dropping unwatched files
+fn classify_event(event: Event, watched_file: Option<&OsStr>, patterns: &[String]) -> Option<FileChange> {
+    if matches!(event.kind, EventKind::Remove(_)) {
+        return Some(FileChange::Removed(event.paths));
+    }
+
+    // Filter for modify events on .log files
+    event
+        .paths
+        .into_iter()
+        .next()
+        .filter(|path| is_watched_target(path, watched_file, patterns))
+        .map(FileChange::Modified)
+}
 
-    // Watch the log directory (non-recursive)
-    watcher
-        .watch(Path::new(&log_directory), RecursiveMode::NonRecursive)
-        .map_err(|e| format!("Failed to watch directory: {}", e))?;
+/// `log-event` payload holding the tail of a changed file
+fn build_log_event(path: &Path, tail_window_bytes: usize, mmap_threshold: u64) -> AppResult<LogEvent> {
+    Ok(LogEvent {
+        file_path: path.display().to_string(),
+        log_type: LogType::from_path(path),
+        content: read_file_tail(path, tail_window_bytes, mmap_threshold)?,
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        file_modified_at: file_modified_at(path),
+    })
+}
 
-    // Spawn async task to handle file events with batching
+/// Read appended text from each changed file and pass it to the parser stage
+fn spawn_file_reader<R: Runtime>(
+    app: AppHandle<R>,
+    mut rx: mpsc::Receiver<FileChange>,
+    tx: mpsc::Sender<FileRead>,
+    mut offsets: HashMap<PathBuf, u64>,
+    counters: Arc<WatcherCounters>,
+    tail_window_bytes: usize,
+    cancel: CancellationToken,
+) {
     tauri::async_runtime::spawn(async move {
-        // Keep watcher alive
-        let _watcher = watcher;
-        
-        let mut last_content: Option<String> = None;
-        let mut last_path: Option<String> = None;
+        let mut headers_read: HashSet<PathBuf> = HashSet::new();
+        let mut corrupt_reads: HashMap<PathBuf, u32> = HashMap::new();
+        loop {
+            let change = tokio::select! {
+                _ = cancel.cancelled() => break,
+                Some(change) = rx.recv() => change,
+                else => break,
+            };
+
+            let path = match change {
+                // A deleted log may come back under the same name (game restart);
+                // forget its offset so the new file is read from the start
+                FileChange::Removed(paths) => {
+                    for path in &paths {
+                        offsets.remove(path);
+                        headers_read.remove(path);
+                        corrupt_reads.remove(path);
+                    }
+                    continue;
+                }
+                FileChange::Modified(path) => path,
+            };
+
+            let mut header = None;
+            if headers_read.insert(path.clone()) {
+                read_log_header(&path)
+                    .map(|parsed| header = parsed)
+                    .log_and_ignore("Failed to read log header");
+            }
+
+            // Parse only what was appended since the last event
+            let new_lines = match read_new_lines(&path, &mut offsets, &mut corrupt_reads) {
+                Ok(NewLines::Text(lines)) => Ok(lines),
+                // Possibly still being written; try again on the next event
+                Ok(NewLines::Corrupted) => continue,
+                // Not a text log after all; reported once, then left behind
+                Ok(NewLines::Skipped) => {
+                    report_corruption(&app, &path);
+                    continue;
+                }
+                Err(e) => Err(e),
+            };
+            counters.record_event(&path, new_lines.as_ref().map_or(0, |lines| lines.len() as u64));
+            if new_lines.is_err() {
+                counters.record_error();
+            }
+            let new_lines = new_lines.ok_or_emit(&app);
+
+            let mmap_threshold = app.state::<AppState>().get_config().mmap_threshold_bytes;
+            let log_event = match build_log_event(&path, tail_window_bytes, mmap_threshold) {
+                Ok(log_event) => Some(log_event),
+                Err(error) => {
+                    counters.record_error();
+                    emit_watcher_error(&app, watcher_error(error));
+                    None
+                }
+            };
+
+            let read = FileRead {
+                log_type: LogType::from_path(&path),
+                header,
+                new_lines,
+                log_event,
+            };
+            if tx.send(read).await.is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Parse new lines and pass them to the emitter stage
+fn spawn_line_parser(mut rx: mpsc::Receiver<FileRead>, tx: mpsc::Sender<ParsedRead>, cancel: CancellationToken) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let read = tokio::select! {
+                _ = cancel.cancelled() => break,
+                Some(read) = rx.recv() => read,
+                else => break,
+            };
+
+            let lines = read
+                .new_lines
+                .map(|new_lines| log_parser::parse_log_content(read.log_type, &new_lines))
+                .unwrap_or_default();
+            let parsed = ParsedRead {
+                header: read.header,
+                lines,
+                log_event: read.log_event,
+            };
+            if tx.send(parsed).await.is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Feed parsed lines to the trackers and flush batched `log-event`s every batch interval
+fn spawn_emitter<R: Runtime>(
+    app: AppHandle<R>,
+    mut rx: mpsc::Receiver<ParsedRead>,
+    interval_ms: Arc<AtomicU64>,
+    buffer_depth: Arc<AtomicUsize>,
+    max_buffered_events: usize,
+    trackers: TrackerHooks<R>,
+    cancel: CancellationToken,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut buffer: VecDeque<LogEvent> = VecDeque::with_capacity(max_buffered_events);
         // Create interval inside async context
-        let mut batch_timer = tokio::time::interval(Duration::from_millis(100));
+        let mut current_interval_ms = interval_ms.load(Ordering::Relaxed);
+        let mut batch_timer = tokio::time::interval(Duration::from_millis(current_interval_ms));
         batch_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+
+                Some(parsed) = rx.recv() => {
+                    if let Some(header) = &parsed.header {
+                        (trackers.on_header)(&app, header);
+                    }
+                    for line in &parsed.lines {
+                        emit_quest_completed(&app, line);
+                        // Updates `raid_status` first; the session tracker reads the ended raid's map from it
+                        emit_raid_event(&app, line);
+                        (trackers.on_line)(&app, line);
+                    }
+                    if let Some(log_event) = parsed.log_event {
+                        buffer_event(&app, &mut buffer, log_event, max_buffered_events);
+                        buffer_depth.store(buffer.len(), Ordering::Relaxed);
+                    }
+                }
+
+                // Emit batched events every batch interval
+                _ = batch_timer.tick() => {
+                    // Pick up interval changes made through the handle
+                    let new_interval_ms = interval_ms.load(Ordering::Relaxed);
+                    if new_interval_ms != current_interval_ms {
+                        current_interval_ms = new_interval_ms;
+                        batch_timer = tokio::time::interval(Duration::from_millis(new_interval_ms));
+                        batch_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                    }
+
+                    for mut log_event in buffer.drain(..) {
+                        log_event.timestamp = chrono::Utc::now().to_rfc3339();
+
+                        // Emit event to frontend
+                        app.emit("log-event", log_event).log_and_ignore("Failed to emit log-event");
+                    }
+                    buffer_depth.store(0, Ordering::Relaxed);
+                }
+            }
+        }
+    });
+}
+
+/// A running reader -> parser -> emitter chain
+struct Pipeline {
+    senders: PipelineSenders,
+    batch_interval_ms: Arc<AtomicU64>,
+    buffer_depth: Arc<AtomicUsize>,
+}
+
+/// Spawn the file reader, line parser and emitter stages, each on its own task
+fn spawn_pipeline<R: Runtime>(
+    app: AppHandle<R>,
+    offsets: HashMap<PathBuf, u64>,
+    counters: Arc<WatcherCounters>,
+    config: &LogWatcherConfig,
+    trackers: TrackerHooks<R>,
+    cancel: CancellationToken,
+) -> Pipeline {
+    let batch_interval_ms = Arc::new(AtomicU64::new(
+        config.batch_interval_ms.clamp(MIN_BATCH_INTERVAL_MS, MAX_BATCH_INTERVAL_MS),
+    ));
+    let buffer_depth = Arc::new(AtomicUsize::new(0));
+
+    let (reader_tx, reader_rx) = mpsc::channel(PIPELINE_CHANNEL_CAPACITY);
+    let (parser_tx, parser_rx) = mpsc::channel(PIPELINE_CHANNEL_CAPACITY);
+    let (emitter_tx, emitter_rx) = mpsc::channel(PIPELINE_CHANNEL_CAPACITY);
+    let senders = PipelineSenders {
+        reader: reader_tx,
+        parser: parser_tx.clone(),
+        emitter: emitter_tx.clone(),
+    };
+
+    spawn_file_reader(
+        app.clone(),
+        reader_rx,
+        parser_tx,
+        offsets,
+        counters,
+        config.tail_window_bytes,
+        cancel.clone(),
+    );
+    spawn_line_parser(parser_rx, emitter_tx, cancel.clone());
+    spawn_emitter(
+        app,
+        emitter_rx,
+        batch_interval_ms.clone(),
+        buffer_depth.clone(),
+        config.max_buffer_depth.max(1),
+        trackers,
+        cancel,
+    );
+
+    Pipeline {
+        senders,
+        batch_interval_ms,
+        buffer_depth,
+    }
+}
+
+/// Start watching a log directory, or a single log file, for changes
+pub fn start_log_watcher(
+    log_path: String,
+    app_handle: AppHandle,
+    config: LogWatcherConfig,
+) -> AppResult<WatcherHandle> {
+    // Validate path exists
+    if !Path::new(&log_path).exists() {
+        return Err(AppError::file_not_found(log_path));
+    }
+
+    // A file is watched through its parent directory, with events filtered to its name
+    let (log_directory, watched_file) = if Path::new(&log_path).is_file() {
+        let parent = Path::new(&log_path)
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        (parent.display().to_string(), Path::new(&log_path).file_name().map(|n| n.to_os_string()))
+    } else {
+        (log_path.clone(), None)
+    };
+
+    // tokio's mpsc, like the stage channels (see `PIPELINE_CHANNEL_CAPACITY`): the event
+    // loop waits on it alongside cancellation under `tokio::select!` without a blocking thread
+    let (tx, mut rx) = mpsc::channel(RAW_EVENT_CHANNEL_CAPACITY);
+    // A single file never needs its parent's subdirectories
+    let mode = if watched_file.is_some() {
+        RecursiveMode::NonRecursive
+    } else {
+        config.recursive_mode()
+    };
+    let counters = Arc::new(WatcherCounters::default());
+    let watcher = create_watcher(
+        Path::new(&log_directory),
+        mode,
+        tx.clone(),
+        raw_overflow_reporter(app_handle.clone(), counters.clone()),
+    )?;
+
+    let offsets = seed_offsets(Path::new(&log_directory), watched_file.as_deref(), &config.file_patterns, mode);
+    let loop_counters = counters.clone();
+    let cancel = CancellationToken::new();
+    let task_cancel = cancel.clone();
+    let stop_reason = Arc::new(Mutex::new(None::<String>));
+    let task_stop_reason = stop_reason.clone();
+    let restarting = Arc::new(AtomicBool::new(false));
+    let task_restarting = restarting.clone();
+
+    let log_path_for_handle = log_path.clone();
+    app_handle.emit("watcher-started", WatcherDirectoryPayload {
+        directory: log_path,
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    }).log_and_ignore("Failed to emit watcher-started");
+
+    // raw event -> file reader -> line parser -> emitter, each on its own task
+    let Pipeline {
+        senders: pipeline,
+        batch_interval_ms,
+        buffer_depth,
+    } = spawn_pipeline(
+        app_handle.clone(),
+        offsets,
+        counters.clone(),
+        &config,
+        APP_TRACKERS,
+        task_cancel.clone(),
+    );
+    let reader_tx = pipeline.reader.clone();
+
+    // Receive notify events and hand watched-file changes to the reader stage
+    tauri::async_runtime::spawn(async move {
+        // Keep watcher alive until the task ends; replaced after a reconnect
+        let mut _watcher = Some(watcher);
+        let mut directory_check = tokio::time::interval(DIRECTORY_CHECK_INTERVAL);
+        let mut last_disk_check: Option<Instant> = None;
         
         loop {
             tokio::select! {
+                _ = task_cancel.cancelled() => {
+                    if task_restarting.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let reason = task_stop_reason
+                        .lock()
+                        .unwrap()
+                        .take()
+                        .unwrap_or_else(|| "stopped".to_string());
+                    app_handle.emit("watcher-stopped", WatcherStoppedPayload {
+                        reason,
+                        timestamp: chrono::Utc::now().to_rfc3339(),
+                    }).log_and_ignore("Failed to emit watcher-stopped");
+                    break;
+                }
+
                 // Process file system events
                 Some(res) = rx.recv() => {
                     match res {
                         Ok(event) => {
-                            // Filter for modify events on .log files
-                            if let Some(path) = event.paths.first() {
-                                if let Some(extension) = path.extension() {
-                                    if extension == "log" && path.file_name().and_then(|n| n.to_str()).map_or(false, |n| n.contains("notifications")) {
-                                        // Read the new content and buffer it
-                                        if let Ok(content) = std::fs::read_to_string(path) {
-                                            // Get last 10KB to avoid reading entire file
-                                            let start = content.len().saturating_sub(10240);
-                                            let recent_content = &content[start..];
-                                            
-                                            last_content = Some(recent_content.to_string());
-                                            last_path = Some(path.display().to_string());
-                                        }
-                                    }
-                                }
+                            let Some(change) = classify_event(event, watched_file.as_deref(), &config.file_patterns) else {
+                                continue;
+                            };
+
+                            if matches!(change, FileChange::Modified(_))
+                                && last_disk_check.is_none_or(|checked| checked.elapsed() >= DISK_SPACE_CHECK_INTERVAL)
+                            {
+                                diagnostics::check_low_disk_space(&app_handle, &log_directory);
+                                last_disk_check = Some(Instant::now());
+                            }
+
+                            // Waits while the reader is behind, bounding the queue
+                            if reader_tx.send(change).await.is_err() {
+                                break;
                             }
                         }
                         Err(e) => {
-                            eprintln!("Watch error: {:?}", e);
-                            let _ = app_handle.emit("log-error", format!("Watch error: {}", e));
+                            emit_watcher_error(&app_handle, watcher_error(AppError::from(e)));
+
+                            // Directory vanished (network drive dropped, drive unmounted)
+                            if !Path::new(&log_directory).exists() {
+                                _watcher = None;
+                                _watcher = reconnect(&app_handle, &log_directory, &tx, &loop_counters, &task_cancel, mode, &config).await;
+                            }
                         }
                     }
                 }
                 
-                // Emit batched events every 100ms
-                _ = batch_timer.tick() => {
-                    if let (Some(content), Some(path)) = (last_content.take(), last_path.take()) {
-                        let log_event = LogEvent {
-                            file_path: path,
-                            content,
-                            timestamp: chrono::Utc::now().to_rfc3339(),
-                        };
-
-                        // Emit event to frontend
-                        let _ = app_handle.emit("log-event", log_event);
+                // Notify doesn't always report a removed root; check for it directly
+                _ = directory_check.tick() => {
+                    if !Path::new(&log_directory).exists() {
+                        _watcher = None;
+                        _watcher = reconnect(&app_handle, &log_directory, &tx, &loop_counters, &task_cancel, mode, &config).await;
                     }
                 }
             }
         }
     });
 
-    Ok(())
+    Ok(WatcherHandle {
+        directory: log_path_for_handle,
+        started_at: Utc::now(),
+        batch_interval_ms,
+        buffer_depth,
+        counters,
+        pipeline,
+        cancel,
+        stop_reason,
+        restarting,
+    })
 }
 
-/// Stop the log watcher (handled by dropping the watcher)
-pub fn stop_log_watcher() -> Result<bool, String> {
-    // In this implementation, stopping is handled by the task ending
-    // A more sophisticated approach would use a cancellation token
+/// Stop the log watcher; the notify watcher is dropped when its task ends
+pub fn stop_log_watcher(handle: WatcherHandle, reason: impl Into<String>) -> AppResult<bool> {
+    handle.stop(reason);
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tauri::test::MockRuntime;
+    use tauri::Listener;
+    use tempfile::TempDir;
+
+    /// Longest wait for an event to come through
+    const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn patterns() -> Vec<String> {
+        LogWatcherConfig::default().file_patterns
+    }
+
+    fn quest_line(quest_id: &str) -> String {
+        format!(
+            "2024-05-01 18:23:45.123 +02:00|0.14.6.0.29862|Info|notifications|Got notification | ChatMessageReceived | {{\"MessageType\": 12, \"message\": {{\"templateId\": \"{} description\"}}}}\n",
+            quest_id
+        )
+    }
+
+    fn completed(quest_id: &str) -> LogLine {
+        LogLine::QuestCompleted {
+            quest_id: quest_id.to_string(),
+        }
+    }
+
+    fn append(path: &Path, bytes: &[u8]) {
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).unwrap();
+        file.write_all(bytes).unwrap();
+        file.sync_all().unwrap();
+    }
+
+    /// What the pipeline sent to the frontend
+    #[derive(Default)]
+    struct Emitted {
+        log_events: Vec<serde_json::Value>,
+        completed_quests: Vec<String>,
+        corrupted_files: Vec<String>,
+        watcher_errors: usize,
+    }
+
+    impl Emitted {
+        fn record(&mut self, name: &str, payload: serde_json::Value) {
+            match name {
+                "log-event" => self.log_events.push(payload),
+                "quest-completed" => self.completed_quests.push(payload["quest_id"].as_str().unwrap().to_string()),
+                "log-corruption-detected" => self.corrupted_files.push(payload["file_path"].as_str().unwrap().to_string()),
+                "watcher-error" => self.watcher_errors += 1,
+                _ => {}
+            }
+        }
+
+        fn completed(&self, quest_id: &str) -> usize {
+            self.completed_quests.iter().filter(|id| *id == quest_id).count()
+        }
+    }
+
+    /// The real reader, parser and emitter stages on a mock app, with the trackers left out
+    struct TestPipeline {
+        _app: tauri::App<MockRuntime>,
+        changes: mpsc::Sender<FileChange>,
+        events: mpsc::UnboundedReceiver<(&'static str, serde_json::Value)>,
+        emitted: Emitted,
+        cancel: CancellationToken,
+    }
+
+    impl TestPipeline {
+        /// Start the stages with offsets seeded from `dir`, as `start_log_watcher` does
+        fn start(dir: &Path) -> Self {
+            let app = tauri::test::mock_app();
+            app.manage(AppState::new());
+            app.handle().plugin(tauri_plugin_store::Builder::default().build()).unwrap();
+
+            let (tx, events) = mpsc::unbounded_channel();
+            for name in ["log-event", "quest-completed", "log-corruption-detected", "watcher-error"] {
+                let tx = tx.clone();
+                app.listen_any(name, move |event| {
+                    let _ = tx.send((name, serde_json::from_str(event.payload()).unwrap()));
+                });
+            }
+
+            let config = LogWatcherConfig {
+                batch_interval_ms: MIN_BATCH_INTERVAL_MS,
+                ..LogWatcherConfig::default()
+            };
+            let offsets = seed_offsets(dir, None, &config.file_patterns, RecursiveMode::NonRecursive);
+            let trackers = TrackerHooks {
+                on_header: |_, _| {},
+                on_line: |_, _| {},
+            };
+            let cancel = CancellationToken::new();
+            let pipeline = spawn_pipeline(app.handle().clone(), offsets, Arc::default(), &config, trackers, cancel.clone());
+
+            Self {
+                _app: app,
+                changes: pipeline.senders.reader,
+                events,
+                emitted: Emitted::default(),
+                cancel,
+            }
+        }
+
+        async fn modified(&self, path: &Path) {
+            self.changes.send(FileChange::Modified(path.to_path_buf())).await.unwrap();
+        }
+
+        async fn removed(&self, path: &Path) {
+            self.changes.send(FileChange::Removed(vec![path.to_path_buf()])).await.unwrap();
+        }
+
+        /// Collect emitted events until `done` holds or `EVENT_TIMEOUT` passes
+        async fn wait_until(&mut self, done: impl Fn(&Emitted) -> bool) -> bool {
+            let deadline = tokio::time::Instant::now() + EVENT_TIMEOUT;
+            while !done(&self.emitted) {
+                match tokio::time::timeout_at(deadline, self.events.recv()).await {
+                    Ok(Some((name, payload))) => self.emitted.record(name, payload),
+                    Ok(None) | Err(_) => return false,
+                }
+            }
+            true
+        }
+    }
+
+    impl Drop for TestPipeline {
+        fn drop(&mut self) {
+            self.cancel.cancel();
+        }
+    }
+
+    fn watch(dir: &Path) -> (RecommendedWatcher, mpsc::Receiver<notify::Result<Event>>) {
+        let (tx, rx) = mpsc::channel(RAW_EVENT_CHANNEL_CAPACITY);
+        let watcher = create_watcher(dir, RecursiveMode::NonRecursive, tx, |_| {}).unwrap();
+        (watcher, rx)
+    }
+
+    #[tokio::test]
+    async fn new_file_is_reported() {
+        let dir = TempDir::new().unwrap();
+        let mut pipeline = TestPipeline::start(dir.path());
+
+        let path = dir.path().join("notifications_1.log");
+        append(&path, quest_line("quest_a").as_bytes());
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_a") == 1 && !e.log_events.is_empty()).await);
+        let event = pipeline.emitted.log_events.last().unwrap();
+        assert_eq!(event["file_path"], path.display().to_string());
+        assert_eq!(event["log_type"], serde_json::to_value(LogType::Notifications).unwrap());
+        assert!(event["content"].as_str().unwrap().contains("quest_a"));
+        assert!(event["file_modified_at"].is_string());
+    }
+
+    #[tokio::test]
+    async fn appended_lines_are_read_once() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        append(&path, quest_line("quest_old").as_bytes());
+
+        let mut pipeline = TestPipeline::start(dir.path());
+        append(&path, quest_line("quest_new").as_bytes());
+        // notify often reports one write several times
+        pipeline.modified(&path).await;
+        pipeline.modified(&path).await;
+        append(&path, quest_line("quest_marker").as_bytes());
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_marker") == 1 && !e.log_events.is_empty()).await);
+        // History from before the watcher started is not replayed
+        assert_eq!(pipeline.emitted.completed("quest_old"), 0);
+        assert_eq!(pipeline.emitted.completed("quest_new"), 1);
+        // The tail still shows the whole file
+        assert!(pipeline.emitted.log_events.last().unwrap()["content"]
+            .as_str()
+            .unwrap()
+            .contains("quest_old"));
+    }
+
+    #[tokio::test]
+    async fn rotated_file_is_read_from_start() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        append(&path, quest_line("quest_before_rotation").repeat(5).as_bytes());
+
+        let mut pipeline = TestPipeline::start(dir.path());
+        std::fs::remove_file(&path).unwrap();
+        pipeline.removed(&path).await;
+        append(&path, quest_line("quest_after_rotation").as_bytes());
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_after_rotation") == 1).await);
+        assert_eq!(pipeline.emitted.completed("quest_before_rotation"), 0);
+    }
+
+    #[tokio::test]
+    async fn recreated_file_does_not_replay_old_content() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        append(&path, quest_line("quest_before_restart").as_bytes());
+
+        let mut pipeline = TestPipeline::start(dir.path());
+        std::fs::remove_file(&path).unwrap();
+        let removal = Event::new(EventKind::Remove(notify::event::RemoveKind::File)).add_path(path.clone());
+        pipeline.changes.send(classify_event(removal, None, &patterns()).unwrap()).await.unwrap();
+
+        // Longer than the old file, so its stale offset would land mid-file rather than past the end
+        let restarted = quest_line("quest_after_restart").repeat(3);
+        append(&path, restarted.as_bytes());
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_after_restart") == 3 && !e.log_events.is_empty()).await);
+        assert_eq!(pipeline.emitted.completed("quest_before_restart"), 0);
+        assert_eq!(pipeline.emitted.log_events.last().unwrap()["content"], restarted);
+    }
+
+    #[tokio::test]
+    async fn utf16_file_is_decoded() {
+        let dir = TempDir::new().unwrap();
+        let mut pipeline = TestPipeline::start(dir.path());
+
+        let path = dir.path().join("notifications_1.log");
+        let mut bytes = UTF16_LE_BOM.to_vec();
+        bytes.extend(quest_line("quest_utf16").encode_utf16().flat_map(u16::to_le_bytes));
+        append(&path, &bytes);
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_utf16") == 1 && !e.log_events.is_empty()).await);
+        assert!(pipeline.emitted.log_events.last().unwrap()["content"]
+            .as_str()
+            .unwrap()
+            .contains("quest_utf16"));
+    }
+
+    #[tokio::test]
+    async fn binary_garbage_is_skipped() {
+        let dir = TempDir::new().unwrap();
+        let mut pipeline = TestPipeline::start(dir.path());
+
+        let path = dir.path().join("notifications_1.log");
+        let mut bytes = quest_line("quest_before_garbage").into_bytes();
+        bytes.extend([0x00, 0xFF, 0xC3, 0x28, 0x9F, b'|', 0x80, b'\n']);
+        bytes.extend(quest_line("quest_after_garbage").as_bytes());
+        append(&path, &bytes);
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_after_garbage") == 1 && !e.log_events.is_empty()).await);
+        assert_eq!(
+            pipeline.emitted.completed_quests,
+            vec!["quest_before_garbage".to_string(), "quest_after_garbage".to_string()]
+        );
+        // The tail is decoded lossily, so the lines around the garbage still reach the frontend
+        let content = pipeline.emitted.log_events.last().unwrap()["content"].as_str().unwrap().to_string();
+        assert!(content.contains("quest_before_garbage"));
+        assert!(content.contains("quest_after_garbage"));
+        assert_eq!(pipeline.emitted.watcher_errors, 0);
+    }
+
+    #[tokio::test]
+    async fn binary_file_is_reported_once() {
+        let dir = TempDir::new().unwrap();
+        let mut pipeline = TestPipeline::start(dir.path());
+
+        let path = dir.path().join("notifications_1.log");
+        append(&path, &[0xC3, 0x28, 0xA0, 0xA1, 0x80, b'\n']);
+        for _ in 0..=MAX_CORRUPT_READS {
+            pipeline.modified(&path).await;
+        }
+        append(&path, quest_line("quest_after_binary").as_bytes());
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| e.completed("quest_after_binary") == 1).await);
+        assert_eq!(pipeline.emitted.corrupted_files, vec![path.display().to_string()]);
+    }
+
+    #[tokio::test]
+    async fn empty_file_yields_no_lines() {
+        let dir = TempDir::new().unwrap();
+        let mut pipeline = TestPipeline::start(dir.path());
+
+        let path = dir.path().join("notifications_1.log");
+        std::fs::File::create(&path).unwrap();
+        pipeline.modified(&path).await;
+
+        assert!(pipeline.wait_until(|e| !e.log_events.is_empty()).await);
+        assert!(pipeline.emitted.completed_quests.is_empty());
+        assert!(pipeline.emitted.log_events.iter().all(|event| event["content"] == ""));
+    }
+
+    #[tokio::test]
+    #[cfg_attr(not(target_os = "windows"), ignore)]
+    async fn directory_can_be_rewatched_after_reappearing() {
+        let root = TempDir::new().unwrap();
+        let dir = root.path().join("Logs");
+        std::fs::create_dir(&dir).unwrap();
+        let (watcher, _rx) = watch(&dir);
+
+        // Windows keeps a watched directory around until its handle is closed
+        drop(watcher);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(!dir.exists());
+        assert!(create_watcher(&dir, RecursiveMode::NonRecursive, mpsc::channel(1).0, |_| {}).is_err());
+
+        // What `reconnect` does once the directory is back
+        std::fs::create_dir(&dir).unwrap();
+        let (_watcher, mut rx) = watch(&dir);
+        let path = dir.join("notifications_1.log");
+        append(&path, quest_line("quest_after_reconnect").as_bytes());
+
+        let reported = tokio::time::timeout(EVENT_TIMEOUT, async {
+            while let Some(event) = rx.recv().await {
+                let change = event.ok().and_then(|event| classify_event(event, None, &patterns()));
+                if matches!(change, Some(FileChange::Modified(changed)) if changed == path) {
+                    return true;
+                }
+            }
+            false
+        })
+        .await;
+        assert!(matches!(reported, Ok(true)));
+    }
+
+    /// `notifications` log from a localized install, saved as UTF-16-LE with a BOM
+    const UTF16_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/notifications_utf16le.log");
+
+    #[test]
+    fn utf16_fixture_is_decoded_and_parsed() {
+        let path = Path::new(UTF16_FIXTURE);
+        let content = read_file_as_string(path).unwrap();
+        assert!(content.starts_with("2024-05-01"));
+        assert!(content.contains("J\u{fc}rgen"));
+        assert_eq!(
+            log_parser::parse_log_content(LogType::from_path(path), &content),
+            vec![completed("5936d90786f7742b1420ba5b")]
+        );
+
+        // Incremental reads decode the same way
+        let mut offsets = HashMap::new();
+        assert_eq!(read_new_lines(path, &mut offsets, &mut HashMap::new()).unwrap(), NewLines::Text(content));
+    }
+
+    #[test]
+    fn mapped_tail_sees_bytes_appended_after_the_first_map() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        let first = quest_line("quest_before_map");
+        append(&path, first.as_bytes());
+
+        // A zero threshold forces the mmap path for any non-empty file
+        assert_eq!(read_file_tail(&path, first.len(), 0).unwrap(), first);
+
+        let second = quest_line("quest_after_map");
+        append(&path, second.as_bytes());
+        assert_eq!(read_file_tail(&path, second.len(), 0).unwrap(), second);
+        assert_eq!(read_file_tail(&path, usize::MAX, 0).unwrap(), format!("{}{}", first, second));
+    }
+
+    #[test]
+    fn mapped_tail_skips_a_split_utf8_sequence() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        append(&path, "J\u{fc}rgen\n".as_bytes());
+
+        // The window starts inside the two-byte `ü`
+        assert_eq!(read_file_tail(&path, 6, 0).unwrap(), "rgen\n");
+    }
+
+    #[test]
+    fn partial_lines_wait_for_their_newline() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        let line = quest_line("quest_partial");
+        let (head, tail) = line.split_at(40);
+        let mut offsets = HashMap::new();
+        let mut corrupt_reads = HashMap::new();
+
+        append(&path, head.as_bytes());
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Text(String::new()));
+
+        append(&path, tail.as_bytes());
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Text(line));
+    }
+
+    #[test]
+    fn corrupted_reads_are_retried_on_the_next_event() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        let mut offsets = HashMap::new();
+        let mut corrupt_reads = HashMap::new();
+
+        append(&path, &[0xC3, 0x28, 0xA0, 0xA1, 0x80, b'\n']);
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Corrupted);
+        assert!(!offsets.contains_key(&path));
+
+        // Once rewritten as text, the whole file is read
+        let line = quest_line("quest_after_corruption");
+        std::fs::write(&path, &line).unwrap();
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Text(line));
+        assert!(corrupt_reads.is_empty());
+    }
+
+    #[test]
+    fn persistently_corrupted_bytes_are_skipped_once() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notifications_1.log");
+        let mut offsets = HashMap::new();
+        let mut corrupt_reads = HashMap::new();
+
+        append(&path, &[0xC3, 0x28, 0xA0, 0xA1, 0x80, b'\n']);
+        for _ in 1..MAX_CORRUPT_READS {
+            assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Corrupted);
+        }
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Skipped);
+        assert_eq!(offsets[&path], 6);
+
+        // Later lines are read past the skipped bytes, which are not reported again
+        let line = quest_line("quest_after_binary");
+        append(&path, line.as_bytes());
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Text(line));
+        assert_eq!(read_new_lines(&path, &mut offsets, &mut corrupt_reads).unwrap(), NewLines::Text(String::new()));
+    }
+
+    #[test]
+    fn application_logs_are_watched_with_an_older_config() {
+        let config = AppConfig {
+            log_file_patterns: vec!["notifications".to_string()],
+            ..AppConfig::default()
+        };
+        let patterns = LogWatcherConfig::from_app_config(&config).file_patterns;
+        assert!(is_watched_log(Path::new("application_1.log"), &patterns));
+        assert!(is_watched_log(Path::new("notifications_1.log"), &patterns));
+    }
+
+    #[test]
+    fn unwatched_files_are_ignored() {
+        let event = Event::new(EventKind::Modify(notify::event::ModifyKind::Any))
+            .add_path(PathBuf::from("C:\\Logs\\traces_1.log"));
+        assert!(classify_event(event, None, &patterns()).is_none());
+
+        let event = Event::new(EventKind::Modify(notify::event::ModifyKind::Any))
+            .add_path(PathBuf::from("C:\\Logs\\application_1.txt"));
+        assert!(classify_event(event, None, &patterns()).is_none());
+    }
+}
@@ -0,0 +1,192 @@
+use crate::app_state::AppState;
+use crate::error::AppResultExt;
+use crate::export::{self, QuestRecordStatus};
+use crate::log_parser::LogLine;
+use crate::store;
+use crate::tarkov_api;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::time::Duration;
+
+/// Store key for the all-time found-in-raid item count
+const ITEMS_FOUND_KEY: &str = "items_found_in_raid";
+
+/// Delay before a changed count is persisted, so a burst of pickups is written once
+const PERSIST_DEBOUNCE: Duration = Duration::from_secs(5);
+
+/// Whether a debounced save of the count is already scheduled
+static PERSIST_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// How pressing a found item is for the quests that need it
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum ItemUrgency {
+    /// An objective asks for the item found in raid, so this copy counts
+    High,
+    /// The item is mentioned by an objective but FIR isn't required
+    Normal,
+}
+
+/// Active quest that mentions a found item
+#[derive(Debug, Clone, Serialize)]
+pub struct QuestItemMatch {
+    pub quest_id: String,
+    pub quest_name: String,
+    pub urgency: ItemUrgency,
+}
+
+/// Payload of the `quest-item-found` event
+#[derive(Debug, Clone, Serialize)]
+pub struct QuestItemFoundPayload {
+    pub item_id: String,
+    pub item_name: String,
+    pub count: u32,
+    pub urgency: ItemUrgency,
+    pub quests: Vec<QuestItemMatch>,
+}
+
+/// Payload of the `insurance-returned` event
+#[derive(Debug, Clone, Serialize)]
+pub struct InsuranceReturnedPayload {
+    pub trader: String,
+    pub item_count: u32,
+}
+
+/// Started quests whose cached objectives mention `item_name`
+fn matching_quests(app: &AppHandle, item_name: &str) -> Vec<QuestItemMatch> {
+    if item_name.is_empty() {
+        return Vec::new();
+    }
+
+    let active: Vec<String> = export::load_records(app)
+        .into_iter()
+        .filter(|record| record.status == QuestRecordStatus::Started)
+        .map(|record| record.id)
+        .collect();
+    let item_name = item_name.to_lowercase();
+
+    tarkov_api::cached_quest_meta(app)
+        .into_iter()
+        .filter(|meta| active.contains(&meta.id))
+        .filter_map(|meta| {
+            let mentions: Vec<String> = meta
+                .objectives
+                .iter()
+                .map(|o| o.to_lowercase())
+                .filter(|o| o.contains(&item_name))
+                .collect();
+            if mentions.is_empty() {
+                return None;
+            }
+
+            let urgency = if mentions.iter().any(|o| o.contains("found in raid")) {
+                ItemUrgency::High
+            } else {
+                ItemUrgency::Normal
+            };
+            Some(QuestItemMatch {
+                quest_id: meta.id,
+                quest_name: meta.name,
+                urgency,
+            })
+        })
+        .collect()
+}
+
+/// Count a found-in-raid item and flag it if an active quest needs it
+pub fn on_log_line(app: &AppHandle, line: &LogLine) {
+    if let LogLine::InsuranceReturn { trader, items } = line {
+        record_insurance_return(app, trader, items.len() as u32);
+        return;
+    }
+    let LogLine::ItemFoundInRaid { item_id, item_name, count } = line else {
+        return;
+    };
+
+    let state = app.state::<AppState>();
+    *state
+        .found_items
+        .lock()
+        .unwrap()
+        .entry(item_id.clone())
+        .or_default() += count;
+    state.items_found_in_raid.fetch_add(u64::from(*count), Ordering::Relaxed);
+    schedule_persist(app);
+
+    let quests = matching_quests(app, item_name);
+    if quests.is_empty() {
+        return;
+    }
+
+    let urgency = if quests.iter().any(|q| q.urgency == ItemUrgency::High) {
+        ItemUrgency::High
+    } else {
+        ItemUrgency::Normal
+    };
+    let _ = app.emit("quest-item-found", QuestItemFoundPayload {
+        item_id: item_id.clone(),
+        item_name: item_name.clone(),
+        count: *count,
+        urgency,
+        quests,
+    });
+}
+
+/// Count insured items handed back by a trader; these were not found in raid
+fn record_insurance_return(app: &AppHandle, trader: &str, item_count: u32) {
+    app.state::<AppState>()
+        .insurance_returns
+        .fetch_add(item_count, Ordering::Relaxed);
+    app.emit(
+        "insurance-returned",
+        InsuranceReturnedPayload {
+            trader: trader.to_string(),
+            item_count,
+        },
+    )
+    .log_and_ignore("Failed to emit insurance-returned");
+}
+
+/// Save the all-time count after `PERSIST_DEBOUNCE`, unless a save is already pending
+fn schedule_persist(app: &AppHandle) {
+    if PERSIST_PENDING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(PERSIST_DEBOUNCE).await;
+        PERSIST_PENDING.store(false, Ordering::Release);
+
+        let count = get_items_found_count(&app);
+        store::save(&app, ITEMS_FOUND_KEY, &count).log_and_ignore("Failed to persist found-in-raid count");
+    });
+}
+
+/// Load the persisted all-time count into `AppState`
+pub fn restore_items_found(app: &AppHandle) {
+    if let Some(count) = store::load::<u64, _>(app, ITEMS_FOUND_KEY) {
+        app.state::<AppState>().items_found_in_raid.store(count, Ordering::Relaxed);
+    }
+}
+
+/// Items found in raid across all sessions
+pub fn get_items_found_count(app: &AppHandle) -> u64 {
+    app.state::<AppState>().items_found_in_raid.load(Ordering::Relaxed)
+}
+
+/// Items found in raid this session
+pub fn get_found_items(app: &AppHandle) -> HashMap<String, u32> {
+    app.state::<AppState>().found_items.lock().unwrap().clone()
+}
+
+/// Insured items returned this session
+pub fn get_insurance_returns_count(app: &AppHandle) -> u32 {
+    app.state::<AppState>().insurance_returns.load(Ordering::Relaxed)
+}
+
+/// Forget items found this session
+pub fn clear_found_items(app: &AppHandle) {
+    app.state::<AppState>().found_items.lock().unwrap().clear();
+}
@@ -0,0 +1,160 @@
+use crate::app_state::AppState;
+use crate::error::AppResult;
+use crate::export;
+use crate::log_parser::LogLine;
+use crate::notifications::{self, NotificationPayload};
+use crate::sound::{self, SoundEffect};
+use crate::store;
+use crate::tarkov_api;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// Store key for user-edited rules
+const STORE_KEY: &str = "notification_rules";
+
+/// How to notify for one `LogLine` type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRule {
+    /// `LogLine` variant name, e.g. `"QuestCompleted"`
+    pub event_type: String,
+    pub enabled: bool,
+    /// Supports `{quest_name}`, `{trader}` and `{map}`
+    pub title_template: String,
+    pub body_template: String,
+    pub play_sound: bool,
+    pub sound_effect: Option<SoundEffect>,
+}
+
+fn rule(
+    event_type: &str,
+    enabled: bool,
+    title: &str,
+    body: &str,
+    sound_effect: Option<SoundEffect>,
+) -> NotificationRule {
+    NotificationRule {
+        event_type: event_type.to_string(),
+        enabled,
+        title_template: title.to_string(),
+        body_template: body.to_string(),
+        play_sound: sound_effect.is_some(),
+        sound_effect,
+    }
+}
+
+/// Rules used until the user saves their own
+pub fn default_rules() -> Vec<NotificationRule> {
+    vec![
+        rule("QuestCompleted", true, "Quest completed", "{quest_name} ({trader})", Some(SoundEffect::QuestComplete)),
+        rule("QuestFailed", true, "Quest failed", "{quest_name} ({trader})", Some(SoundEffect::Error)),
+        rule("QuestStarted", false, "Quest started", "{quest_name} ({trader})", None),
+        rule("TraderLevelUp", true, "Loyalty level up", "{trader} loyalty increased", Some(SoundEffect::LevelUp)),
+        rule("RaidStarted", false, "Raid started", "Deploying to {map}", None),
+        rule("RaidEnded", false, "Raid ended", "Back from raid", None),
+    ]
+}
+
+/// Saved rules, or the defaults
+pub fn get_rules(app: &AppHandle) -> Vec<NotificationRule> {
+    store::load(app, STORE_KEY).unwrap_or_else(default_rules)
+}
+
+pub fn save_rules(app: &AppHandle, rules: &[NotificationRule]) -> AppResult<()> {
+    store::save(app, STORE_KEY, &rules)
+}
+
+pub fn reset_rules(app: &AppHandle) -> AppResult<Vec<NotificationRule>> {
+    let rules = default_rules();
+    save_rules(app, &rules)?;
+    Ok(rules)
+}
+
+/// Placeholder values available for a line
+#[derive(Default)]
+struct TemplateValues {
+    quest_name: String,
+    trader: String,
+    map: String,
+}
+
+impl TemplateValues {
+    fn for_line(app: &AppHandle, line: &LogLine) -> Self {
+        let mut values = TemplateValues::default();
+
+        match line {
+            LogLine::QuestStarted { quest_id }
+            | LogLine::QuestFailed { quest_id }
+            | LogLine::QuestCompleted { quest_id } => {
+                // Prefer cached tarkov.dev metadata, then local records, then the raw ID
+                if let Some(meta) = tarkov_api::cached_quest_meta(app).into_iter().find(|m| &m.id == quest_id) {
+                    values.quest_name = meta.name;
+                    values.trader = meta.trader;
+                    values.map = meta.map.unwrap_or_default();
+                } else if let Some(record) = export::load_records(app).into_iter().find(|r| &r.id == quest_id) {
+                    values.quest_name = record.name;
+                    values.trader = record.trader;
+                } else {
+                    values.quest_name = quest_id.clone();
+                }
+            }
+            LogLine::TraderLevelUp { trader, .. } => values.trader = trader.clone(),
+            LogLine::RaidStarted { map, .. } => values.map = map.clone(),
+            _ => {}
+        }
+
+        values
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{quest_name}", &self.quest_name)
+            .replace("{trader}", &self.trader)
+            .replace("{map}", &self.map)
+    }
+}
+
+/// Variant name of a line, matching `NotificationRule::event_type`
+fn event_type(line: &LogLine) -> Option<String> {
+    let value = serde_json::to_value(line).ok()?;
+    value["type"].as_str().map(str::to_string)
+}
+
+/// Show notifications and play sounds for every enabled rule matching `line`
+pub fn on_log_line(app: &AppHandle, line: &LogLine) {
+    let state = app.state::<AppState>();
+    if !state.get_config().notifications_enabled {
+        return;
+    }
+
+    let Some(event_type) = event_type(line) else {
+        return;
+    };
+    let rules: Vec<NotificationRule> = get_rules(app)
+        .into_iter()
+        .filter(|rule| rule.enabled && rule.event_type == event_type)
+        .collect();
+    if rules.is_empty() {
+        return;
+    }
+
+    let values = TemplateValues::for_line(app, line);
+    for rule in rules {
+        let payload = NotificationPayload {
+            title: values.render(&rule.title_template),
+            body: values.render(&rule.body_template),
+            hero_image: None,
+            actions: Vec::new(),
+            tag: event_type.clone(),
+        };
+        if let Err(e) = notifications::send_notification(app, &payload) {
+            eprintln!("Failed to show {} notification: {}", event_type, e);
+        }
+
+        if rule.play_sound {
+            let effect = rule.sound_effect.unwrap_or(SoundEffect::QuestComplete);
+            if let Err(e) = sound::play_sound(&state, effect) {
+                eprintln!("Failed to play {} sound: {}", event_type, e);
+            }
+        }
+    }
+}
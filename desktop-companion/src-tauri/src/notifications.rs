@@ -0,0 +1,113 @@
+use crate::error::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use windows::core::{Interface, HSTRING, IInspectable};
+use windows::Data::Xml::Dom::XmlDocument;
+use windows::Foundation::TypedEventHandler;
+use windows::UI::Notifications::{ToastActivatedEventArgs, ToastNotification, ToastNotificationManager};
+
+/// Quest toasts carry at most two buttons ("View Quest", "Mark All Complete")
+const MAX_ACTIONS: usize = 2;
+
+/// Button shown on a toast
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationAction {
+    /// Button label, e.g. "View Quest"
+    pub label: String,
+    /// Tag sent back in the `notification-action` event when clicked
+    pub action: String,
+}
+
+/// Toast content sent from the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPayload {
+    pub title: String,
+    pub body: String,
+    #[serde(default)]
+    pub hero_image: Option<String>,
+    #[serde(default)]
+    pub actions: Vec<NotificationAction>,
+    /// Toasts with the same tag replace each other
+    pub tag: String,
+}
+
+/// Payload of the `notification-action` event
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationActionEvent {
+    pub tag: String,
+    pub action: String,
+}
+
+fn notification_error(error: impl std::fmt::Display) -> AppError {
+    AppError::new("NOTIFICATION_ERROR", "Failed to show notification").with_details(error.to_string())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Compose the toast XML for a payload
+pub fn build_toast_xml(payload: &NotificationPayload) -> String {
+    let mut xml = String::from("<toast><visual><binding template=\"ToastGeneric\">");
+    xml.push_str(&format!("<text>{}</text>", escape_xml(&payload.title)));
+    xml.push_str(&format!("<text>{}</text>", escape_xml(&payload.body)));
+    if let Some(image) = &payload.hero_image {
+        xml.push_str(&format!("<image placement=\"hero\" src=\"{}\"/>", escape_xml(image)));
+    }
+    xml.push_str("</binding></visual>");
+
+    if !payload.actions.is_empty() {
+        xml.push_str("<actions>");
+        for action in payload.actions.iter().take(MAX_ACTIONS) {
+            xml.push_str(&format!(
+                "<action content=\"{}\" arguments=\"{}\" activationType=\"foreground\"/>",
+                escape_xml(&action.label),
+                escape_xml(&action.action)
+            ));
+        }
+        xml.push_str("</actions>");
+    }
+
+    xml.push_str("</toast>");
+    xml
+}
+
+/// Show a toast and forward button clicks as `notification-action` events
+pub fn send_notification(app: &AppHandle, payload: &NotificationPayload) -> AppResult<()> {
+    let document = XmlDocument::new().map_err(notification_error)?;
+    document
+        .LoadXml(&HSTRING::from(build_toast_xml(payload)))
+        .map_err(notification_error)?;
+
+    let toast = ToastNotification::CreateToastNotification(&document).map_err(notification_error)?;
+    toast.SetTag(&HSTRING::from(&payload.tag)).map_err(notification_error)?;
+
+    let handle = app.clone();
+    let tag = payload.tag.clone();
+    toast
+        .Activated(&TypedEventHandler::new(
+            move |_: &Option<ToastNotification>, args: &Option<IInspectable>| {
+                // Body clicks arrive with empty arguments; only buttons carry an action
+                if let Some(args) = args.as_ref().and_then(|a| a.cast::<ToastActivatedEventArgs>().ok()) {
+                    let action = args.Arguments()?.to_string();
+                    if !action.is_empty() {
+                        let _ = handle.emit(
+                            "notification-action",
+                            NotificationActionEvent { tag: tag.clone(), action },
+                        );
+                    }
+                }
+                Ok(())
+            },
+        ))
+        .map_err(notification_error)?;
+
+    let app_id = HSTRING::from(&app.config().identifier);
+    ToastNotificationManager::CreateToastNotifierWithId(&app_id)
+        .and_then(|notifier| notifier.Show(&toast))
+        .map_err(notification_error)
+}
@@ -0,0 +1,189 @@
+use crate::error::{AppError, AppResult};
+use crate::sync_queue::{SyncOperation, SyncQueue};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Quest state as encoded in BSG's profile JSON
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum BsgQuestStatus {
+    Locked,
+    AvailableForStart,
+    Started,
+    AvailableForFinish,
+    Success,
+    Fail,
+    FailRestartable,
+    MarkedAsFailed,
+    Expired,
+    AvailableAfter,
+}
+
+impl BsgQuestStatus {
+    /// Map BSG's integer status code
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::Locked),
+            1 => Some(Self::AvailableForStart),
+            2 => Some(Self::Started),
+            3 => Some(Self::AvailableForFinish),
+            4 => Some(Self::Success),
+            5 => Some(Self::Fail),
+            6 => Some(Self::FailRestartable),
+            7 => Some(Self::MarkedAsFailed),
+            8 => Some(Self::Expired),
+            9 => Some(Self::AvailableAfter),
+            _ => None,
+        }
+    }
+
+    /// Whether the quest counts as done for syncing purposes
+    pub fn is_importable(&self) -> bool {
+        matches!(self, Self::Success | Self::AvailableForFinish)
+    }
+}
+
+/// A single quest entry from `character.Quests`
+#[derive(Debug, Clone, Serialize)]
+pub struct QuestStatus {
+    pub id: String,
+    pub status: BsgQuestStatus,
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+/// A trader entry from `character.TraderStandings`
+#[derive(Debug, Clone, Serialize)]
+pub struct TraderStanding {
+    pub loyalty_level: u8,
+    pub standing: f64,
+    pub sales_sum: i64,
+    pub unlocked: bool,
+}
+
+/// Everything read from a BSG Launcher profile file
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileImport {
+    pub level: u8,
+    pub quests: Vec<QuestStatus>,
+    pub trader_standings: HashMap<String, TraderStanding>,
+}
+
+/// Outcome of pushing an import to the sync queue
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileImportSummary {
+    pub level: u8,
+    pub quests_found: usize,
+    /// Quests that were (or, on a dry run, would be) queued for sync
+    pub importable_quests: Vec<QuestStatus>,
+    pub trader_standings: HashMap<String, TraderStanding>,
+    pub dry_run: bool,
+}
+
+/// Read a numeric field that BSG sometimes writes as a string
+fn number_field(json: &Value, key: &str) -> Option<f64> {
+    match json.get(key)? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn parse_quest(entry: &Value) -> Option<QuestStatus> {
+    let id = entry.get("qid").and_then(Value::as_str)?.to_string();
+    let code = number_field(entry, "status")? as u8;
+    let Some(status) = BsgQuestStatus::from_code(code) else {
+        eprintln!("Skipping quest {} with unknown status {}", id, code);
+        return None;
+    };
+    // BSG writes 0 for quests that were never started
+    let started_at = number_field(entry, "startTime")
+        .filter(|secs| *secs > 0.0)
+        .and_then(|secs| DateTime::from_timestamp(secs as i64, 0));
+
+    Some(QuestStatus {
+        id,
+        status,
+        started_at,
+    })
+}
+
+fn parse_trader_standing(entry: &Value) -> TraderStanding {
+    TraderStanding {
+        loyalty_level: number_field(entry, "loyaltyLevel").unwrap_or(1.0) as u8,
+        standing: number_field(entry, "standing").unwrap_or(0.0),
+        sales_sum: number_field(entry, "salesSum").unwrap_or(0.0) as i64,
+        unlocked: entry.get("unlocked").and_then(Value::as_bool).unwrap_or(false),
+    }
+}
+
+/// Parse the profile JSON already loaded into memory
+fn parse_profile(json: &Value) -> AppResult<ProfileImport> {
+    let character = json
+        .get("character")
+        .ok_or_else(|| AppError::parse_error("Profile has no character section"))?;
+
+    let level = character
+        .get("Info")
+        .and_then(|info| number_field(info, "Level"))
+        .ok_or_else(|| AppError::parse_error("Profile has no character level"))? as u8;
+
+    let quests = character
+        .get("Quests")
+        .and_then(Value::as_array)
+        .map(|entries| entries.iter().filter_map(parse_quest).collect())
+        .unwrap_or_default();
+
+    let trader_standings = character
+        .get("TraderStandings")
+        .and_then(Value::as_object)
+        .map(|traders| {
+            traders
+                .iter()
+                .map(|(id, entry)| (id.clone(), parse_trader_standing(entry)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ProfileImport {
+        level,
+        quests,
+        trader_standings,
+    })
+}
+
+/// Parse a BSG Launcher profile file
+pub fn import_profile(path: &Path) -> Result<ProfileImport, AppError> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| AppError::file_read_error(path.display().to_string(), e))?;
+    let json: Value = serde_json::from_str(&content).map_err(AppError::parse_error)?;
+    parse_profile(&json)
+}
+
+/// Quests from the import that should be pushed to Supabase
+pub fn importable_quests(import: &ProfileImport) -> Vec<QuestStatus> {
+    import
+        .quests
+        .iter()
+        .filter(|quest| quest.status.is_importable())
+        .cloned()
+        .collect()
+}
+
+/// Queue an upsert for each quest
+pub fn queue_quests(queue: &SyncQueue, user_id: &str, quests: &[QuestStatus]) {
+    let now = Utc::now().to_rfc3339();
+    for quest in quests {
+        queue.enqueue(
+            SyncOperation::Upsert,
+            json!({
+                "user_id": user_id,
+                "quest_id": quest.id,
+                "completed": true,
+                "completed_at": now,
+                "updated_at": now,
+            }),
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use crate::app_state::{AppState, QuestCache, QUEST_CACHE_CAPACITY};
+use crate::error::AppResultExt;
+use crate::export::{self, QuestRecord, QuestRecordStatus};
+use crate::log_parser::LogLine;
+use crate::store;
+use crate::system_tray;
+use crate::tarkov_api;
+use crate::telemetry;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// Store key for the total number of quests reported by tarkov.dev
+const QUEST_TOTAL_KEY: &str = "quest_total";
+
+/// Overall quest completion counts
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct QuestStats {
+    pub completed: u32,
+    pub total: u32,
+}
+
+impl QuestStats {
+    /// Completion as a whole percentage
+    pub fn percent(&self) -> u32 {
+        (self.completed * 100).checked_div(self.total).unwrap_or(0)
+    }
+}
+
+/// Number of completions shown in the tray's "Recent Completions" submenu
+pub const RECENT_COMPLETIONS_LIMIT: usize = 5;
+
+/// Store key for the recent completions cache
+const QUEST_CACHE_KEY: &str = "quest_cache";
+
+/// A completed quest, for recent-activity lists
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestCompletion {
+    pub quest_id: String,
+    pub quest_name: String,
+    pub trader: String,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// The `limit` most recently completed quests, newest first
+pub fn recent_completions(records: &[QuestRecord], limit: usize) -> Vec<QuestCompletion> {
+    let mut completions: Vec<QuestCompletion> = records
+        .iter()
+        .filter(|r| r.status == QuestRecordStatus::Completed)
+        .filter_map(|r| {
+            r.completed_at.map(|completed_at| QuestCompletion {
+                quest_id: r.id.clone(),
+                quest_name: r.name.clone(),
+                trader: r.trader.clone(),
+                completed_at,
+            })
+        })
+        .collect();
+
+    completions.sort_by_key(|c| std::cmp::Reverse(c.completed_at));
+    completions.truncate(limit);
+    completions
+}
+
+/// Restore the recent completions cache, seeding it from quest records on first run
+pub fn restore_quest_cache(app: &AppHandle) {
+    let cache = store::load::<QuestCache, _>(app, QUEST_CACHE_KEY).unwrap_or_else(|| {
+        let mut cache = QuestCache::new(QUEST_CACHE_CAPACITY);
+        for completion in recent_completions(&export::load_records(app), QUEST_CACHE_CAPACITY)
+            .into_iter()
+            .rev()
+        {
+            cache.push(completion);
+        }
+        cache
+    });
+
+    *app.state::<AppState>().quest_cache.lock().unwrap() = cache;
+}
+
+/// Add a completion to the cache and persist it
+fn record_completion(app: &AppHandle, completion: QuestCompletion) {
+    let cache = {
+        let state = app.state::<AppState>();
+        let mut cache = state.quest_cache.lock().unwrap();
+        cache.push(completion);
+        cache.clone()
+    };
+
+    store::save(app, QUEST_CACHE_KEY, &cache).log_and_ignore("Failed to persist quest cache");
+    telemetry::track(app, telemetry::TelemetryEvent::QuestCompleted);
+}
+
+/// Count completed records against the known quest total
+pub fn compute_stats(records: &[QuestRecord], known_total: u32) -> QuestStats {
+    let completed = records
+        .iter()
+        .filter(|r| r.status == QuestRecordStatus::Completed)
+        .count() as u32;
+
+    QuestStats {
+        completed,
+        total: known_total.max(records.len() as u32),
+    }
+}
+
+/// Recompute `QuestStats` from the stored records and refresh the tray tooltip
+pub fn refresh_stats(app: &AppHandle) {
+    let known_total = store::load::<u32, _>(app, QUEST_TOTAL_KEY).unwrap_or_default();
+    let stats = compute_stats(&export::load_records(app), known_total);
+
+    let state = app.state::<AppState>();
+    if state.get_quest_stats() != stats {
+        state.set_quest_stats(stats);
+        system_tray::refresh_tray_tooltip(app);
+    }
+}
+
+/// Fetch the current quest total from tarkov.dev in the background
+pub fn spawn_total_refresh(app: AppHandle) {
+    refresh_stats(&app);
+
+    tauri::async_runtime::spawn(async move {
+        match tarkov_api::fetch_quest_count().await {
+            Ok(total) => {
+                store::save(&app, QUEST_TOTAL_KEY, &total).log_and_ignore("Failed to persist quest total");
+                refresh_stats(&app);
+            }
+            Err(e) => e.log("Failed to fetch quest total"),
+        }
+    });
+}
+
+/// Update the stored quest record for a quest lifecycle line
+pub fn on_log_line(app: &AppHandle, line: &LogLine) {
+    let (quest_id, status) = match line {
+        LogLine::QuestStarted { quest_id } => (quest_id, QuestRecordStatus::Started),
+        LogLine::QuestCompleted { quest_id } => (quest_id, QuestRecordStatus::Completed),
+        LogLine::QuestFailed { quest_id } => (quest_id, QuestRecordStatus::Failed),
+        _ => return,
+    };
+
+    let mut records = export::load_records(app);
+    let index = match records.iter().position(|r| &r.id == quest_id) {
+        Some(index) => index,
+        None => {
+            let meta = tarkov_api::cached_quest_meta(app)
+                .into_iter()
+                .find(|meta| &meta.id == quest_id);
+
+            records.push(QuestRecord {
+                id: quest_id.clone(),
+                name: meta.as_ref().map(|m| m.name.clone()).unwrap_or_else(|| quest_id.clone()),
+                trader: meta.map(|m| m.trader).unwrap_or_default(),
+                status,
+                completed_at: None,
+                notes: None,
+            });
+            records.len() - 1
+        }
+    };
+
+    let record = &mut records[index];
+    record.status = status;
+    if status == QuestRecordStatus::Completed {
+        let completed_at = Utc::now();
+        record.completed_at = Some(completed_at);
+        record_completion(app, QuestCompletion {
+            quest_id: record.id.clone(),
+            quest_name: record.name.clone(),
+            trader: record.trader.clone(),
+            completed_at,
+        });
+    }
+
+    if let Err(e) = export::save_records(app, &records) {
+        e.log("Failed to save quest records");
+        return;
+    }
+
+    refresh_stats(app);
+
+    if status == QuestRecordStatus::Completed {
+        system_tray::refresh_tray_menu(app);
+    }
+}
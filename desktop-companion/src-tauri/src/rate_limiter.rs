@@ -0,0 +1,86 @@
+use std::sync::Mutex;
+use tokio::time::{Duration, Instant};
+
+/// Lowest accepted rate, so a zero or negative config value can't stall callers forever
+const MIN_RATE_PER_SECOND: f64 = 0.1;
+
+/// Token bucket shared by every caller of `acquire`
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.refilled_at = now;
+    }
+
+    /// Take a token, or return how long until one may be available
+    fn try_take(&mut self, now: Instant) -> Option<Duration> {
+        if let Some(until) = self.paused_until {
+            if now < until {
+                return Some(until - now);
+            }
+            self.paused_until = None;
+        }
+
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Allow `rate` calls per second on average, in bursts of up to `burst`
+    pub fn new(rate: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            bucket: Mutex::new(Bucket {
+                rate: rate.max(MIN_RATE_PER_SECOND),
+                burst,
+                tokens: burst,
+                refilled_at: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// Change the refill rate; tokens already in the bucket are kept
+    pub fn set_rate(&self, rate: f64) {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(Instant::now());
+        bucket.rate = rate.max(MIN_RATE_PER_SECOND);
+    }
+
+    /// Hold every caller for `duration`, e.g. the server's `Retry-After`
+    pub fn pause(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.paused_until = Some(bucket.paused_until.map_or(until, |current| current.max(until)));
+        bucket.tokens = 0.0;
+    }
+
+    /// Wait until a token is available and take it
+    pub async fn acquire(&self) {
+        loop {
+            let wait = self.bucket.lock().unwrap().try_take(Instant::now());
+            match wait {
+                None => return,
+                Some(delay) => tokio::time::sleep(delay).await,
+            }
+        }
+    }
+}
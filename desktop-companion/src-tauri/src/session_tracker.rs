@@ -0,0 +1,386 @@
+use crate::app_state::{AppState, RaidStatus};
+use crate::error::AppResultExt;
+use crate::log_parser::{LogFileHeader, LogLine};
+use crate::store;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Store key for all-time totals
+const ALL_TIME_KEY: &str = "all_time_stats";
+
+/// Store key for the latest loyalty level of each trader
+const TRADER_LEVELS_KEY: &str = "trader_levels";
+
+/// Store key for the latest Fence reputation
+const FENCE_REPUTATION_KEY: &str = "fence_reputation";
+
+/// Store key for the last detected character name
+const CHARACTER_NAME_KEY: &str = "character_name";
+
+/// `AppState.fence_reputation` holds the reputation multiplied by this
+const FENCE_REPUTATION_SCALE: f32 = 100.0;
+
+/// Totals for a single map
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MapStats {
+    pub raids: u32,
+    pub survived: u32,
+    pub kills: u32,
+}
+
+/// Totals for the current app session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionStats {
+    pub raids_started: u32,
+    pub raids_survived: u32,
+    pub raids_died: u32,
+    pub kills: u32,
+    pub quests_completed: u32,
+    pub per_map: HashMap<String, MapStats>,
+    /// Flea market sale proceeds after fees
+    pub flea_earnings_rub: u64,
+    pub session_start: DateTime<Utc>,
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self {
+            raids_started: 0,
+            raids_survived: 0,
+            raids_died: 0,
+            kills: 0,
+            quests_completed: 0,
+            per_map: HashMap::new(),
+            flea_earnings_rub: 0,
+            session_start: Utc::now(),
+        }
+    }
+}
+
+/// Totals across every session, persisted in the store
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AllTimeStats {
+    pub raids_started: u32,
+    pub raids_survived: u32,
+    pub raids_died: u32,
+    pub kills: u32,
+    pub quests_completed: u32,
+    #[serde(default)]
+    pub per_map: HashMap<String, MapStats>,
+}
+
+/// Session stats together with all-time totals, as returned over IPC
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionStatsReport {
+    #[serde(flatten)]
+    pub session: SessionStats,
+    pub all_time: AllTimeStats,
+}
+
+/// Payload of the `skill-leveled-up` event
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillLevelUpPayload {
+    pub skill: String,
+    pub new_level: f32,
+}
+
+/// Payload of the `character-detected` event
+#[derive(Debug, Clone, Serialize)]
+pub struct CharacterDetectedPayload {
+    pub character_name: String,
+    pub profile_id: Option<String>,
+    pub game_version: String,
+}
+
+/// Payload of the `account-switched` event
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountSwitchedPayload {
+    pub previous: String,
+    pub current: String,
+}
+
+/// Payload of the `flea-market-sale` event
+#[derive(Debug, Clone, Serialize)]
+pub struct FleaMarketSalePayload {
+    pub item_name: String,
+    pub count: u32,
+    pub price_rub: u64,
+    pub fee: u64,
+    pub net_rub: u64,
+    /// Session earnings including this sale
+    pub session_earnings_rub: u64,
+}
+
+/// Fence reputation levels worth warning about when crossed downwards
+const KARMA_THRESHOLDS: [f32; 2] = [0.0, -1.0];
+
+/// Payload of the `karma-threshold-crossed` event
+#[derive(Debug, Clone, Serialize)]
+pub struct KarmaThresholdPayload {
+    pub threshold: f32,
+    pub previous: f32,
+    pub current: f32,
+    pub reason: String,
+}
+
+/// Counter deltas a log line contributes
+#[derive(Default)]
+struct Delta {
+    raids_started: u32,
+    raids_survived: u32,
+    raids_died: u32,
+    kills: u32,
+    quests_completed: u32,
+    /// Map the raid counters belong to, when known
+    map: Option<String>,
+}
+
+impl Delta {
+    /// `raid` is the current `AppState.raid_status`, which names the map a `RaidEnded` belongs to
+    fn from_line(line: &LogLine, raid: &RaidStatus) -> Option<Self> {
+        let delta = match line {
+            LogLine::RaidStarted { map, .. } => Delta {
+                raids_started: 1,
+                map: Some(map.clone()),
+                ..Default::default()
+            },
+            LogLine::RaidEnded { survived, kills } => Delta {
+                raids_survived: u32::from(*survived),
+                raids_died: u32::from(!*survived),
+                kills: *kills,
+                map: raid.map().map(str::to_string),
+                ..Default::default()
+            },
+            LogLine::QuestCompleted { .. } => Delta { quests_completed: 1, ..Default::default() },
+            _ => return None,
+        };
+        Some(delta)
+    }
+
+    fn apply_to_map(&self, per_map: &mut HashMap<String, MapStats>) {
+        let Some(map) = &self.map else {
+            return;
+        };
+        let stats = per_map.entry(map.clone()).or_default();
+        stats.raids += self.raids_started;
+        stats.survived += self.raids_survived;
+        stats.kills += self.kills;
+    }
+}
+
+/// Add a parsed line to the session and all-time totals
+pub fn on_log_line(app: &AppHandle, line: &LogLine) {
+    if let LogLine::SkillLevelUp { skill, new_level } = line {
+        record_skill_level(app, skill, *new_level);
+        return;
+    }
+    if let LogLine::ScavKarmaChange { delta, new_value, reason } = line {
+        record_karma_change(app, *delta, *new_value, reason);
+        return;
+    }
+    if let LogLine::TraderLevelUp { trader, level } = line {
+        record_trader_level(app, trader, *level);
+    }
+    if let LogLine::FleaMarketSold { item_name, count, price_rub, fee } = line {
+        record_flea_sale(app, item_name, *count, *price_rub, *fee);
+        return;
+    }
+
+    let Some(delta) = Delta::from_line(line, &app.state::<AppState>().get_raid_status()) else {
+        return;
+    };
+
+    {
+        let state = app.state::<AppState>();
+        let mut session = state.session_stats.lock().unwrap();
+        session.raids_started += delta.raids_started;
+        session.raids_survived += delta.raids_survived;
+        session.raids_died += delta.raids_died;
+        session.kills += delta.kills;
+        session.quests_completed += delta.quests_completed;
+        delta.apply_to_map(&mut session.per_map);
+    }
+
+    let mut all_time = load_all_time(app);
+    all_time.raids_started += delta.raids_started;
+    all_time.raids_survived += delta.raids_survived;
+    all_time.raids_died += delta.raids_died;
+    all_time.kills += delta.kills;
+    all_time.quests_completed += delta.quests_completed;
+    delta.apply_to_map(&mut all_time.per_map);
+    store::save(app, ALL_TIME_KEY, &all_time).log_and_ignore("Failed to persist all-time stats");
+}
+
+/// Remember a skill's latest level and announce it
+fn record_skill_level(app: &AppHandle, skill: &str, new_level: f32) {
+    app.state::<AppState>()
+        .skill_levels
+        .lock()
+        .unwrap()
+        .insert(skill.to_string(), new_level);
+
+    let _ = app.emit("skill-leveled-up", SkillLevelUpPayload {
+        skill: skill.to_string(),
+        new_level,
+    });
+}
+
+/// Add a sale's net profit to the session earnings and announce it
+fn record_flea_sale(app: &AppHandle, item_name: &str, count: u32, price_rub: u64, fee: u64) {
+    let net_rub = price_rub.saturating_sub(fee);
+    let session_earnings_rub = {
+        let state = app.state::<AppState>();
+        let mut session = state.session_stats.lock().unwrap();
+        session.flea_earnings_rub += net_rub;
+        session.flea_earnings_rub
+    };
+
+    app.emit(
+        "flea-market-sale",
+        FleaMarketSalePayload {
+            item_name: item_name.to_string(),
+            count,
+            price_rub,
+            fee,
+            net_rub,
+            session_earnings_rub,
+        },
+    )
+    .log_and_ignore("Failed to emit flea-market-sale");
+}
+
+/// Remember a trader's latest loyalty level
+fn record_trader_level(app: &AppHandle, trader: &str, level: u8) {
+    let levels = {
+        let state = app.state::<AppState>();
+        let mut levels = state.trader_levels.lock().unwrap();
+        levels.insert(trader.to_string(), level);
+        levels.clone()
+    };
+
+    store::save(app, TRADER_LEVELS_KEY, &levels).log_and_ignore("Failed to persist trader levels");
+}
+
+/// Apply a karma change and warn when it drops through a threshold
+fn record_karma_change(app: &AppHandle, delta: f32, new_value: Option<f32>, reason: &str) {
+    let previous = get_fence_reputation(app);
+
+    // Start from the backend's absolute value when we have one, so missed
+    // changes (e.g. before the watcher started) don't leave us drifting
+    let current = match new_value {
+        Some(value) => value,
+        None => previous + delta,
+    };
+    app.state::<AppState>()
+        .fence_reputation
+        .store((current * FENCE_REPUTATION_SCALE).round() as i32, Ordering::Relaxed);
+
+    store::save(app, FENCE_REPUTATION_KEY, &current).log_and_ignore("Failed to persist Fence reputation");
+
+    for threshold in KARMA_THRESHOLDS {
+        if previous >= threshold && current < threshold {
+            let _ = app.emit("karma-threshold-crossed", KarmaThresholdPayload {
+                threshold,
+                previous,
+                current,
+                reason: reason.to_string(),
+            });
+        }
+    }
+}
+
+/// Record the character from a new log file's header, announcing account switches
+pub fn on_log_header(app: &AppHandle, header: &LogFileHeader) {
+    let Some(character_name) = header.character_name.clone() else {
+        return;
+    };
+
+    let previous = app
+        .state::<AppState>()
+        .character_name
+        .lock()
+        .unwrap()
+        .replace(character_name.clone());
+
+    let _ = app.emit("character-detected", CharacterDetectedPayload {
+        character_name: character_name.clone(),
+        profile_id: header.profile_id.clone(),
+        game_version: header.game_version.clone(),
+    });
+
+    match previous {
+        Some(previous) if previous == character_name => return,
+        Some(previous) => {
+            let _ = app.emit("account-switched", AccountSwitchedPayload {
+                previous,
+                current: character_name.clone(),
+            });
+        }
+        None => {}
+    }
+
+    store::save(app, CHARACTER_NAME_KEY, &character_name).log_and_ignore("Failed to persist character name");
+}
+
+/// Latest known Fence reputation
+pub fn get_fence_reputation(app: &AppHandle) -> f32 {
+    app.state::<AppState>().fence_reputation.load(Ordering::Relaxed) as f32 / FENCE_REPUTATION_SCALE
+}
+
+/// Latest known loyalty level of every trader seen in the logs
+pub fn get_trader_levels(app: &AppHandle) -> HashMap<String, u8> {
+    app.state::<AppState>().trader_levels.lock().unwrap().clone()
+}
+
+/// Restore trader levels, Fence reputation and character name saved by a previous run
+pub fn restore_progression(app: &AppHandle) {
+    let state = app.state::<AppState>();
+
+    if let Some(levels) = store::load::<HashMap<String, u8>, _>(app, TRADER_LEVELS_KEY) {
+        *state.trader_levels.lock().unwrap() = levels;
+    }
+    if let Some(reputation) = store::load::<f32, _>(app, FENCE_REPUTATION_KEY) {
+        state
+            .fence_reputation
+            .store((reputation * FENCE_REPUTATION_SCALE).round() as i32, Ordering::Relaxed);
+    }
+    if let Some(character_name) = store::load::<String, _>(app, CHARACTER_NAME_KEY) {
+        *state.character_name.lock().unwrap() = Some(character_name);
+    }
+}
+
+/// Latest known level of every skill seen in the logs
+pub fn get_skill_levels(app: &AppHandle) -> HashMap<String, f32> {
+    app.state::<AppState>().skill_levels.lock().unwrap().clone()
+}
+
+/// All-time totals from the store
+pub fn load_all_time(app: &AppHandle) -> AllTimeStats {
+    store::load(app, ALL_TIME_KEY).unwrap_or_default()
+}
+
+/// Current session stats merged with all-time totals
+pub fn get_session_stats(app: &AppHandle) -> SessionStatsReport {
+    SessionStatsReport {
+        session: app.state::<AppState>().get_session_stats(),
+        all_time: load_all_time(app),
+    }
+}
+
+/// Current session stats for each map played
+pub fn get_session_stats_by_map(app: &AppHandle) -> HashMap<String, MapStats> {
+    app.state::<AppState>().session_stats.lock().unwrap().per_map.clone()
+}
+
+/// Net flea market earnings this session
+pub fn get_flea_earnings(app: &AppHandle) -> u64 {
+    app.state::<AppState>().session_stats.lock().unwrap().flea_earnings_rub
+}
+
+/// Start a fresh session (all-time totals are kept)
+pub fn reset_session_stats(app: &AppHandle) {
+    *app.state::<AppState>().session_stats.lock().unwrap() = SessionStats::default();
+}
@@ -0,0 +1,73 @@
+use crate::deep_link;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Loopback address the primary instance listens on
+const INSTANCE_ADDR: &str = "127.0.0.1:47291";
+
+/// Message asking the primary instance to bring its window to front
+const ACTIVATE_MESSAGE: &str = "activate";
+
+/// Prefix for forwarding a deep link URI to the primary instance
+const DEEP_LINK_PREFIX: &str = "deep-link ";
+
+/// Claim the primary-instance socket.
+///
+/// If another instance is already listening it is asked to activate (and
+/// handle `deep_link`, if any) and this process exits. Returns `None` when
+/// the port is taken by something that does not answer, in which case the
+/// app keeps running as a non-primary instance.
+pub fn acquire(deep_link: Option<&str>) -> Option<TcpListener> {
+    match TcpListener::bind(INSTANCE_ADDR) {
+        Ok(listener) => Some(listener),
+        Err(_) => {
+            let mut messages = vec![ACTIVATE_MESSAGE.to_string()];
+            if let Some(uri) = deep_link {
+                messages.push(format!("{}{}", DEEP_LINK_PREFIX, uri));
+            }
+
+            if send_messages(&messages).is_ok() {
+                std::process::exit(0);
+            }
+            None
+        }
+    }
+}
+
+/// Send newline-delimited messages to the primary instance
+fn send_messages(messages: &[String]) -> std::io::Result<()> {
+    let addr = INSTANCE_ADDR.parse().expect("valid instance address");
+    let mut stream = TcpStream::connect_timeout(&addr, Duration::from_millis(500))?;
+    for message in messages {
+        writeln!(stream, "{}", message)?;
+    }
+    stream.flush()
+}
+
+/// Accept messages from later instances on a background thread
+pub fn listen(app: AppHandle, listener: TcpListener) {
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+
+            for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                let line = line.trim();
+                if line == ACTIVATE_MESSAGE {
+                    show_main_window(&app);
+                } else if let Some(uri) = line.strip_prefix(DEEP_LINK_PREFIX) {
+                    deep_link::handle_uri(&app, uri);
+                }
+            }
+        }
+    });
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
@@ -0,0 +1,78 @@
+use crate::app_state::AppState;
+use crate::error::{AppError, AppResult};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use std::sync::mpsc;
+
+/// Built-in sound effects
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum SoundEffect {
+    QuestComplete,
+    LevelUp,
+    RaidDied,
+    SyncComplete,
+    Error,
+}
+
+impl SoundEffect {
+    fn bytes(&self) -> &'static [u8] {
+        match self {
+            SoundEffect::QuestComplete => include_bytes!("../sounds/quest_complete.wav"),
+            SoundEffect::LevelUp => include_bytes!("../sounds/level_up.wav"),
+            SoundEffect::RaidDied => include_bytes!("../sounds/raid_died.wav"),
+            SoundEffect::SyncComplete => include_bytes!("../sounds/sync_complete.wav"),
+            SoundEffect::Error => include_bytes!("../sounds/error.wav"),
+        }
+    }
+}
+
+fn sound_error(error: impl std::fmt::Display) -> AppError {
+    AppError::warning("SOUND_ERROR", "Failed to play sound").with_details(error.to_string())
+}
+
+/// Open the default output device on a dedicated thread.
+///
+/// `OutputStream` is not `Send`, so the owning thread parks forever to keep
+/// the device open and hands back a shareable handle.
+fn open_output() -> AppResult<OutputStreamHandle> {
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::spawn(move || match OutputStream::try_default() {
+        Ok((_stream, handle)) => {
+            let _ = tx.send(Ok(handle));
+            loop {
+                std::thread::park();
+            }
+        }
+        Err(e) => {
+            let _ = tx.send(Err(sound_error(e)));
+        }
+    });
+
+    rx.recv().map_err(sound_error)?
+}
+
+/// Play an effect at the configured volume, opening the output device on first use
+pub fn play_sound(state: &AppState, effect: SoundEffect) -> AppResult<()> {
+    let volume = state.get_config().notification_volume.clamp(0.0, 1.0);
+
+    let mut output = state.audio_output.lock()?;
+    let handle = match output.as_ref() {
+        Some(handle) => handle.clone(),
+        None => {
+            let handle = open_output()?;
+            *output = Some(handle.clone());
+            handle
+        }
+    };
+    drop(output);
+
+    let source = Decoder::new(Cursor::new(effect.bytes())).map_err(sound_error)?;
+    let sink = Sink::try_new(&handle).map_err(sound_error)?;
+    sink.set_volume(volume);
+    sink.append(source);
+    sink.detach();
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::error::AppResult;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+/// Store file shared by all backend modules
+pub const STORE_FILE: &str = "companion.json";
+
+/// Load a value from the persistent store, returning `None` if missing or malformed
+pub fn load<T: DeserializeOwned, R: Runtime>(app: &AppHandle<R>, key: &str) -> Option<T> {
+    let store = app.store(STORE_FILE).ok()?;
+    let value = store.get(key)?;
+    serde_json::from_value(value).ok()
+}
+
+/// Write a value to the persistent store and flush it to disk
+pub fn save<T: Serialize, R: Runtime>(app: &AppHandle<R>, key: &str, value: &T) -> AppResult<()> {
+    let store = app.store(STORE_FILE)?;
+    store.set(key, serde_json::to_value(value)?);
+    store.save()?;
+    Ok(())
+}
+
+/// Remove every key from the persistent store and flush it to disk
+pub fn clear<R: Runtime>(app: &AppHandle<R>) -> AppResult<()> {
+    let store = app.store(STORE_FILE)?;
+    store.clear();
+    store.save()?;
+    Ok(())
+}
@@ -0,0 +1,292 @@
+use crate::app_state::AppConfig;
+use crate::error::{AppError, AppResult};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+
+/// Table holding per-user quest completion state
+pub const QUEST_PROGRESS_TABLE: &str = "quest_progress";
+
+/// Unique key columns of the quest progress table
+pub const QUEST_PROGRESS_CONFLICT_KEYS: &str = "user_id,quest_id";
+
+/// Request timeout for Supabase REST calls
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Timeout for reachability pings
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome of a reachability check against the Supabase URL
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PingResult {
+    pub reachable: bool,
+    pub latency_ms: u64,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+}
+
+/// Send an unauthenticated HEAD request to `url` and time the round trip.
+///
+/// Any HTTP response below 500 counts as reachable, so a rejected or missing
+/// API key is reported through `status_code` rather than as a connectivity failure.
+pub async fn ping_url(url: &str) -> PingResult {
+    let http = reqwest::Client::builder()
+        .timeout(PING_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+
+    let started = Instant::now();
+    let result = http.head(url).send().await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(response) => {
+            let status = response.status();
+            PingResult {
+                reachable: !status.is_server_error(),
+                latency_ms,
+                status_code: Some(status.as_u16()),
+                error: status
+                    .is_server_error()
+                    .then(|| format!("Supabase responded with HTTP {}", status.as_u16())),
+            }
+        }
+        Err(e) => PingResult {
+            reachable: false,
+            latency_ms,
+            status_code: None,
+            error: Some(AppError::from(e).to_string()),
+        },
+    }
+}
+
+/// Tables the companion reads and writes, checked by `validate`
+const REQUIRED_TABLES: &[&str] = &[QUEST_PROGRESS_TABLE];
+
+/// Step-by-step outcome of checking the Supabase URL, key and tables
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SupabaseValidationResult {
+    pub url_reachable: bool,
+    pub key_valid: bool,
+    pub tables_accessible: Vec<String>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Minimal client for the Supabase REST (PostgREST) API
+#[derive(Clone)]
+pub struct SupabaseClient {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: String,
+}
+
+impl SupabaseClient {
+    pub fn new(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: api_key.into(),
+        }
+    }
+
+    /// Build a client from the app config, if Supabase is configured
+    pub fn from_config(config: &AppConfig) -> Option<Self> {
+        match (&config.supabase_url, &config.supabase_key) {
+            (Some(url), Some(key)) if !url.is_empty() && !key.is_empty() => {
+                Some(Self::new(url.clone(), key.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    fn rest_url(&self, table: &str) -> String {
+        format!("{}/rest/v1/{}", self.base_url, table)
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.http
+            .request(method, url)
+            .header("apikey", &self.api_key)
+            .bearer_auth(&self.api_key)
+    }
+
+    /// Check that the REST endpoint answers, returning the round-trip time in milliseconds
+    pub async fn ping(&self) -> AppResult<u64> {
+        let started = Instant::now();
+        let response = self
+            .request(Method::GET, &format!("{}/rest/v1/", self.base_url))
+            .send()
+            .await?;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        if response.status().is_server_error() {
+            return Err(AppError::network_error(format!(
+                "Supabase responded with HTTP {}",
+                response.status().as_u16()
+            )));
+        }
+
+        Ok(latency_ms)
+    }
+
+    /// Make authenticated calls to the REST root and each required table, stopping at the first failure
+    pub async fn validate(&self) -> SupabaseValidationResult {
+        let mut result = SupabaseValidationResult {
+            url_reachable: false,
+            key_valid: false,
+            tables_accessible: Vec::new(),
+            latency_ms: 0,
+            error: None,
+        };
+
+        let started = Instant::now();
+        let response = self
+            .request(Method::GET, &format!("{}/rest/v1/", self.base_url))
+            .send()
+            .await;
+        result.latency_ms = started.elapsed().as_millis() as u64;
+
+        let status = match response {
+            Ok(response) => response.status(),
+            Err(e) => {
+                result.error = Some(AppError::from(e).to_string());
+                return result;
+            }
+        };
+        if status.is_server_error() {
+            result.error = Some(format!("Supabase responded with HTTP {}", status.as_u16()));
+            return result;
+        }
+        result.url_reachable = true;
+
+        if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
+            result.error = Some("API key was rejected".to_string());
+            return result;
+        }
+        result.key_valid = true;
+
+        for table in REQUIRED_TABLES {
+            let response = self
+                .request(Method::GET, &self.rest_url(table))
+                .query(&[("select", "*"), ("limit", "0")])
+                .send()
+                .await;
+
+            match response {
+                Ok(response) if response.status().is_success() => {
+                    result.tables_accessible.push(table.to_string());
+                }
+                Ok(response) if response.status() == StatusCode::NOT_FOUND => {
+                    result.error = Some(format!("Table not found: {}", table));
+                    return result;
+                }
+                Ok(response) => {
+                    result.error = Some(format!(
+                        "Table {} responded with HTTP {}",
+                        table,
+                        response.status().as_u16()
+                    ));
+                    return result;
+                }
+                Err(e) => {
+                    result.error = Some(AppError::from(e).to_string());
+                    return result;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Insert or update a row, resolving conflicts on `on_conflict` columns
+    pub async fn upsert(&self, table: &str, payload: &Value, on_conflict: &str) -> AppResult<()> {
+        let response = self
+            .request(Method::POST, &self.rest_url(table))
+            .query(&[("on_conflict", on_conflict)])
+            .header("Prefer", "resolution=merge-duplicates,return=minimal")
+            .json(payload)
+            .send()
+            .await?;
+
+        check_status(response).await.map(|_| ())
+    }
+
+    /// Fetch the first row matching every column/value pair in `filters`
+    pub async fn fetch_one(&self, table: &str, filters: &Value) -> AppResult<Option<Value>> {
+        let mut query = eq_filters(filters)?;
+        query.push(("select".to_string(), "*".to_string()));
+        query.push(("limit".to_string(), "1".to_string()));
+
+        let response = self
+            .request(Method::GET, &self.rest_url(table))
+            .query(&query)
+            .send()
+            .await?;
+
+        let rows: Vec<Value> = check_status(response).await?.json().await?;
+        Ok(rows.into_iter().next())
+    }
+
+    /// Delete rows matching every column/value pair in `filters`
+    pub async fn delete(&self, table: &str, filters: &Value) -> AppResult<()> {
+        let response = self
+            .request(Method::DELETE, &self.rest_url(table))
+            .query(&eq_filters(filters)?)
+            .send()
+            .await?;
+
+        check_status(response).await.map(|_| ())
+    }
+}
+
+/// Convert a JSON object into PostgREST `column=eq.value` query pairs
+fn eq_filters(filters: &Value) -> AppResult<Vec<(String, String)>> {
+    let object = filters
+        .as_object()
+        .filter(|o| !o.is_empty())
+        .ok_or_else(|| AppError::invalid_config("delete filters must be a non-empty object"))?;
+
+    Ok(object
+        .iter()
+        .map(|(column, value)| {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (column.clone(), format!("eq.{}", value))
+        })
+        .collect())
+}
+
+/// Map non-success HTTP responses to the matching `AppError`
+async fn check_status(response: Response) -> AppResult<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    // Only the delay-seconds form of Retry-After is used by Supabase
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+
+    let body = response.text().await.unwrap_or_default();
+    Err(match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => AppError::auth_error(body),
+        StatusCode::TOO_MANY_REQUESTS => match retry_after {
+            Some(retry_after) => AppError::rate_limited().with_retry_after(retry_after),
+            None => AppError::rate_limited(),
+        },
+        _ => AppError::database_error(format!("HTTP {}: {}", status.as_u16(), body)),
+    })
+}
@@ -0,0 +1,196 @@
+use crate::app_state::DebugRedacted;
+use crate::error::{AppError, AppResult};
+use crate::supabase::QUEST_PROGRESS_TABLE;
+use crate::system_tray::{self, ConnectionStatus};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Emitter};
+use tokio::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_util::sync::CancellationToken;
+
+/// Phoenix heartbeat interval expected by Supabase Realtime
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(25);
+
+/// Reference used for the channel join message
+const JOIN_REF: &str = "1";
+
+/// Connection details for a realtime subscription
+#[derive(Clone)]
+pub struct RealtimeConfig {
+    pub supabase_url: String,
+    pub api_key: String,
+    pub user_id: String,
+    pub access_token: Option<String>,
+}
+
+impl std::fmt::Debug for RealtimeConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RealtimeConfig")
+            .field("supabase_url", &self.supabase_url)
+            .field("api_key", &DebugRedacted(&self.api_key))
+            .field("user_id", &self.user_id)
+            .field("access_token", &self.access_token.as_ref().map(DebugRedacted))
+            .finish()
+    }
+}
+
+impl RealtimeConfig {
+    fn websocket_url(&self) -> String {
+        let base = self.supabase_url.trim_end_matches('/');
+        let base = base
+            .strip_prefix("https://")
+            .map(|rest| format!("wss://{}", rest))
+            .or_else(|| base.strip_prefix("http://").map(|rest| format!("ws://{}", rest)))
+            .unwrap_or_else(|| base.to_string());
+
+        format!("{}/realtime/v1/websocket?apikey={}&vsn=1.0.0", base, self.api_key)
+    }
+
+    fn topic(&self) -> String {
+        format!("realtime:{}:{}", QUEST_PROGRESS_TABLE, self.user_id)
+    }
+}
+
+/// Handle to a running realtime subscription
+pub struct RealtimeHandle {
+    cancel: CancellationToken,
+}
+
+impl RealtimeHandle {
+    /// Stop the subscription and its reconnect loop
+    pub fn stop(&self) {
+        self.cancel.cancel();
+    }
+}
+
+/// Subscribe to server-side quest progress changes for the configured user.
+///
+/// Reconnects with exponential backoff until the returned handle is stopped.
+pub fn start_realtime_sync(app: AppHandle, config: RealtimeConfig) -> RealtimeHandle {
+    let cancel = CancellationToken::new();
+    let task_cancel = cancel.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = AppError::network_error("Realtime connection lost");
+
+        loop {
+            match run_session(&app, &config, &task_cancel, &mut backoff).await {
+                Ok(()) => break,
+                Err(e) => {
+                    eprintln!("Realtime subscription error: {}", e);
+                    let _ = system_tray::update_tray_icon_status(&app, ConnectionStatus::Disconnected);
+                    if !e.recoverable {
+                        break;
+                    }
+                }
+            }
+
+            let delay = backoff.next_retry_delay();
+            backoff.increment_retry();
+
+            tokio::select! {
+                _ = task_cancel.cancelled() => break,
+                _ = tokio::time::sleep(delay) => {}
+            }
+        }
+    });
+
+    RealtimeHandle { cancel }
+}
+
+/// Run a single websocket session; returns `Ok` only when cancelled
+async fn run_session(
+    app: &AppHandle,
+    config: &RealtimeConfig,
+    cancel: &CancellationToken,
+    backoff: &mut AppError,
+) -> AppResult<()> {
+    let (mut socket, _) = tokio_tungstenite::connect_async(config.websocket_url())
+        .await
+        .map_err(AppError::network_error)?;
+
+    let topic = config.topic();
+    let join = json!({
+        "topic": topic,
+        "event": "phx_join",
+        "payload": {
+            "config": {
+                "postgres_changes": [{
+                    "event": "*",
+                    "schema": "public",
+                    "table": QUEST_PROGRESS_TABLE,
+                    "filter": format!("user_id=eq.{}", config.user_id),
+                }],
+            },
+            "access_token": config.access_token.as_deref().unwrap_or(&config.api_key),
+        },
+        "ref": JOIN_REF,
+        "join_ref": JOIN_REF,
+    });
+    socket
+        .send(Message::Text(join.to_string()))
+        .await
+        .map_err(AppError::network_error)?;
+
+    let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+    let mut heartbeat_ref: u64 = 1;
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => {
+                let _ = socket.close(None).await;
+                return Ok(());
+            }
+
+            _ = heartbeat.tick() => {
+                heartbeat_ref += 1;
+                let message = json!({
+                    "topic": "phoenix",
+                    "event": "heartbeat",
+                    "payload": {},
+                    "ref": heartbeat_ref.to_string(),
+                });
+                socket
+                    .send(Message::Text(message.to_string()))
+                    .await
+                    .map_err(AppError::network_error)?;
+            }
+
+            message = socket.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => {
+                        return Err(AppError::network_error("Realtime socket closed"));
+                    }
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(AppError::network_error(e)),
+                };
+
+                let Ok(message) = serde_json::from_str::<Value>(&text) else {
+                    continue;
+                };
+                if message["topic"] != topic.as_str() {
+                    continue;
+                }
+
+                match message["event"].as_str() {
+                    Some("phx_reply") if message["ref"] == JOIN_REF => {
+                        if message["payload"]["status"] != "ok" {
+                            return Err(AppError::auth_error(message["payload"]["response"].to_string()));
+                        }
+                        backoff.retry_count = 0;
+                        let _ = system_tray::update_tray_icon_status(app, ConnectionStatus::Subscribed);
+                    }
+                    Some("postgres_changes") => {
+                        let _ = app.emit("quest-synced-from-server", message["payload"]["data"].clone());
+                    }
+                    Some("phx_error") | Some("phx_close") => {
+                        return Err(AppError::network_error("Realtime channel closed by server"));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,496 @@
+use crate::app_state::{AppConfig, AppState};
+use crate::error::{retry_with_backoff, AppError, AppResult};
+use crate::rate_limiter::RateLimiter;
+use crate::store;
+use crate::supabase::{SupabaseClient, QUEST_PROGRESS_CONFLICT_KEYS, QUEST_PROGRESS_TABLE};
+use crate::system_tray::{self, ConnectionStatus};
+use crate::telemetry;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Store key for the persisted queue
+const STORE_KEY: &str = "sync_queue";
+
+/// Store key for unresolved conflicts
+const CONFLICTS_STORE_KEY: &str = "sync_conflicts";
+
+/// Store key for the last successful sync timestamp
+pub const LAST_SYNCED_AT_KEY: &str = "last_synced_at";
+
+/// Attempts per item within a single flush
+const FLUSH_ATTEMPTS: u32 = 3;
+
+/// Writes allowed back-to-back before `sync_rate_limit_per_second` applies
+const SYNC_RATE_LIMIT_BURST: u32 = 10;
+
+/// Pause after an HTTP 429 that didn't say how long to wait
+const DEFAULT_RATE_LIMIT_PAUSE: Duration = Duration::from_secs(1);
+
+/// How often the background task drains the queue
+const FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Write operation to replay against Supabase
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum SyncOperation {
+    Upsert,
+    Delete,
+}
+
+/// Pending write waiting to be pushed to Supabase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncItem {
+    pub id: String,
+    pub operation: SyncOperation,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub attempts: u32,
+}
+
+/// Local write that collided with a newer server record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConflict {
+    pub local: SyncItem,
+    pub server: Value,
+}
+
+/// User's choice for settling a conflict
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ConflictResolution {
+    TakeLocal,
+    TakeServer,
+    Merge(Value),
+}
+
+/// Outcome of a queue flush
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FlushSummary {
+    pub pushed: u32,
+    pub failed: u32,
+    pub dropped: u32,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// Outcome of a forced sync
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncSummary {
+    pub pushed: u32,
+    pub failed: u32,
+    pub conflicts: u32,
+    pub duration_ms: u64,
+}
+
+/// Progress of a forced sync, emitted as `sync-progress`
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncProgress {
+    pub current: u32,
+    pub total: u32,
+}
+
+/// Overall time budget for a forced sync
+const FORCE_SYNC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Result of replaying one queued write
+enum ApplyOutcome {
+    Pushed,
+    Conflict(Value),
+}
+
+/// Offline write queue persisted to `tauri-plugin-store`
+pub struct SyncQueue {
+    items: Mutex<VecDeque<SyncItem>>,
+    conflicts: Mutex<HashMap<String, SyncConflict>>,
+    rate_limiter: RateLimiter,
+    app: OnceLock<AppHandle>,
+}
+
+impl SyncQueue {
+    pub fn new() -> Self {
+        Self {
+            items: Mutex::new(VecDeque::new()),
+            conflicts: Mutex::new(HashMap::new()),
+            rate_limiter: RateLimiter::new(
+                AppConfig::default().sync_rate_limit_per_second,
+                SYNC_RATE_LIMIT_BURST,
+            ),
+            app: OnceLock::new(),
+        }
+    }
+
+    /// Attach the queue to the app store and restore persisted items
+    pub fn attach(&self, app: &AppHandle) {
+        if self.app.set(app.clone()).is_err() {
+            return;
+        }
+
+        if let Some(saved) = store::load::<VecDeque<SyncItem>, _>(app, STORE_KEY) {
+            let mut items = self.items.lock().unwrap();
+            for item in saved.into_iter().rev() {
+                items.push_front(item);
+            }
+        }
+
+        if let Some(saved) = store::load::<HashMap<String, SyncConflict>, _>(app, CONFLICTS_STORE_KEY) {
+            self.conflicts.lock().unwrap().extend(saved);
+        }
+    }
+
+    /// Add a write to the back of the queue, returning its ID
+    pub fn enqueue(&self, operation: SyncOperation, payload: Value) -> String {
+        self.enqueue_item(SyncItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            operation,
+            payload,
+            created_at: Utc::now(),
+            attempts: 0,
+        })
+    }
+
+    fn enqueue_item(&self, item: SyncItem) -> String {
+        let id = item.id.clone();
+        self.items.lock().unwrap().push_back(item);
+        self.persist();
+        id
+    }
+
+    /// Settle a conflict reported by a previous flush
+    pub fn resolve_conflict(&self, item_id: &str, resolution: ConflictResolution) -> AppResult<()> {
+        let conflict = self.conflicts.lock().unwrap().remove(item_id).ok_or_else(|| {
+            AppError::new("CONFLICT_NOT_FOUND", "No pending conflict with this ID")
+                .with_details(item_id.to_string())
+                .non_recoverable()
+        })?;
+
+        // Re-stamped items are newer than the server record, so they push cleanly
+        let mut local = conflict.local;
+        local.created_at = Utc::now();
+        local.attempts = 0;
+
+        match resolution {
+            ConflictResolution::TakeLocal => {
+                self.enqueue_item(local);
+            }
+            ConflictResolution::TakeServer => self.persist(),
+            ConflictResolution::Merge(merged) => {
+                local.payload = merged;
+                self.enqueue_item(local);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drop every queued item and pending conflict
+    pub fn clear(&self) {
+        self.items.lock().unwrap().clear();
+        self.conflicts.lock().unwrap().clear();
+        self.persist();
+    }
+
+    /// Apply a new `sync_rate_limit_per_second`
+    pub fn set_rate_limit(&self, per_second: f64) {
+        self.rate_limiter.set_rate(per_second);
+    }
+
+    /// Replay one item once a rate-limit token is free, pausing every write
+    /// when Supabase answers HTTP 429
+    async fn apply_limited(&self, client: &SupabaseClient, item: &SyncItem) -> AppResult<ApplyOutcome> {
+        self.rate_limiter.acquire().await;
+
+        let result = apply(client, item).await;
+        if let Err(error) = &result {
+            if error.code == "RATE_LIMITED" {
+                let pause = error
+                    .retry_after_secs
+                    .map_or(DEFAULT_RATE_LIMIT_PAUSE, |secs| Duration::from_secs(u64::from(secs)));
+                self.rate_limiter.pause(pause);
+                if let Some(app) = self.app.get() {
+                    error.emit_to_frontend(app);
+                }
+            }
+        }
+        result
+    }
+
+    /// Number of items waiting to be pushed
+    pub fn depth(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    /// Try to push every queued item to Supabase.
+    ///
+    /// Items that keep failing are re-queued until their retry limit is
+    /// exceeded, then dropped. A network failure stops the flush early since
+    /// the remaining items would fail the same way.
+    pub async fn flush(&self, client: &SupabaseClient) -> FlushSummary {
+        let pending: Vec<SyncItem> = self.items.lock().unwrap().drain(..).collect();
+        let mut summary = FlushSummary::default();
+        let mut requeue = VecDeque::new();
+        let mut pending = pending.into_iter();
+
+        for mut item in pending.by_ref() {
+            match retry_with_backoff(|| self.apply_limited(client, &item), FLUSH_ATTEMPTS).await {
+                Ok(ApplyOutcome::Pushed) => summary.pushed += 1,
+                Ok(ApplyOutcome::Conflict(server)) => {
+                    let conflict = SyncConflict { local: item, server };
+                    self.conflicts
+                        .lock()
+                        .unwrap()
+                        .insert(conflict.local.id.clone(), conflict.clone());
+                    summary.conflicts.push(conflict);
+                }
+                Err(mut error) => {
+                    item.attempts += 1;
+                    error.retry_count = item.attempts;
+
+                    if !error.recoverable || error.max_retries_exceeded() {
+                        let dropped = AppError::sync_error(format!("Dropped sync item {}", item.id)).with_cause(&error);
+                        match self.app.get() {
+                            Some(app) => dropped.emit_to_frontend(app),
+                            None => dropped.log("Sync error"),
+                        }
+                        summary.dropped += 1;
+                        continue;
+                    }
+
+                    summary.failed += 1;
+                    requeue.push_back(item);
+
+                    if matches!(error.code.as_str(), "NETWORK_ERROR" | "TIMEOUT") {
+                        break;
+                    }
+                }
+            }
+        }
+        requeue.extend(pending);
+
+        {
+            // Failed items go back ahead of anything enqueued during the flush
+            let mut items = self.items.lock().unwrap();
+            for item in requeue.into_iter().rev() {
+                items.push_front(item);
+            }
+        }
+        self.persist();
+
+        summary
+    }
+
+    /// Push every queued item once, in order, without backoff between attempts.
+    ///
+    /// Items that fail, or that were not reached before the time budget ran
+    /// out, go back to the front of the queue.
+    pub async fn force_flush(&self, client: &SupabaseClient, app: &AppHandle) -> SyncSummary {
+        let started = std::time::Instant::now();
+        let deadline = tokio::time::Instant::now() + FORCE_SYNC_TIMEOUT;
+        let pending: Vec<SyncItem> = self.items.lock().unwrap().drain(..).collect();
+        let total = pending.len() as u32;
+        let mut summary = SyncSummary::default();
+        let mut requeue = VecDeque::new();
+        let mut pending = pending.into_iter();
+
+        for (index, mut item) in pending.by_ref().enumerate() {
+            let result = tokio::time::timeout_at(deadline, self.apply_limited(client, &item)).await;
+
+            match result {
+                Ok(Ok(ApplyOutcome::Pushed)) => summary.pushed += 1,
+                Ok(Ok(ApplyOutcome::Conflict(server))) => {
+                    let conflict = SyncConflict { local: item, server };
+                    self.conflicts
+                        .lock()
+                        .unwrap()
+                        .insert(conflict.local.id.clone(), conflict.clone());
+                    let _ = app.emit("sync-conflict", &conflict);
+                    summary.conflicts += 1;
+                }
+                Ok(Err(_)) | Err(_) => {
+                    item.attempts += 1;
+                    summary.failed += 1;
+                    requeue.push_back(item);
+                }
+            }
+
+            let _ = app.emit("sync-progress", SyncProgress {
+                current: index as u32 + 1,
+                total,
+            });
+
+            if tokio::time::Instant::now() >= deadline {
+                break;
+            }
+        }
+
+        for item in pending {
+            summary.failed += 1;
+            requeue.push_back(item);
+        }
+
+        {
+            let mut items = self.items.lock().unwrap();
+            for item in requeue.into_iter().rev() {
+                items.push_front(item);
+            }
+        }
+        self.persist();
+
+        summary.duration_ms = started.elapsed().as_millis() as u64;
+        summary
+    }
+
+    fn persist(&self) {
+        if let Some(app) = self.app.get() {
+            let items = self.items.lock().unwrap().clone();
+            let conflicts = self.conflicts.lock().unwrap().clone();
+
+            let result = store::save(app, STORE_KEY, &items)
+                .and_then(|_| store::save(app, CONFLICTS_STORE_KEY, &conflicts));
+            if let Err(e) = result {
+                eprintln!("Failed to persist sync queue: {}", e);
+            }
+        }
+    }
+}
+
+impl Default for SyncQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Replay a single queued write, refusing to overwrite newer server records
+async fn apply(client: &SupabaseClient, item: &SyncItem) -> AppResult<ApplyOutcome> {
+    let result = match item.operation {
+        SyncOperation::Upsert => match server_record_if_newer(client, item).await {
+            Ok(Some(server)) => return Ok(ApplyOutcome::Conflict(server)),
+            Ok(None) => client
+                .upsert(QUEST_PROGRESS_TABLE, &item.payload, QUEST_PROGRESS_CONFLICT_KEYS)
+                .await
+                .map(|_| ApplyOutcome::Pushed),
+            Err(e) => Err(e),
+        },
+        SyncOperation::Delete => client
+            .delete(QUEST_PROGRESS_TABLE, &item.payload)
+            .await
+            .map(|_| ApplyOutcome::Pushed),
+    };
+
+    result.map_err(|e| e.with_correlation_id(item.id.clone()))
+}
+
+/// Fetch the server row for an item if it was updated after the item was queued
+async fn server_record_if_newer(client: &SupabaseClient, item: &SyncItem) -> AppResult<Option<Value>> {
+    let mut filters = serde_json::Map::new();
+    for key in QUEST_PROGRESS_CONFLICT_KEYS.split(',') {
+        match item.payload.get(key) {
+            Some(value) => {
+                filters.insert(key.to_string(), value.clone());
+            }
+            // Without the row key there is nothing to compare against
+            None => return Ok(None),
+        }
+    }
+
+    let Some(server) = client.fetch_one(QUEST_PROGRESS_TABLE, &Value::Object(filters)).await? else {
+        return Ok(None);
+    };
+
+    let server_updated_at = server
+        .get("updated_at")
+        .and_then(Value::as_str)
+        .and_then(parse_server_timestamp);
+
+    match server_updated_at {
+        Some(updated_at) if updated_at > item.created_at => Ok(Some(server)),
+        _ => Ok(None),
+    }
+}
+
+/// Parse a Postgres timestamp, treating zone-less values as UTC
+fn parse_server_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|t| t.and_utc())
+        })
+}
+
+/// Notify the frontend about conflicts found during a flush
+pub fn emit_conflicts(app: &AppHandle, summary: &FlushSummary) {
+    for conflict in &summary.conflicts {
+        let _ = app.emit("sync-conflict", conflict);
+    }
+}
+
+/// Record a successful sync in `AppState` and persist the timestamp
+pub fn record_successful_sync(app: &AppHandle) {
+    let now = Utc::now();
+    app.state::<AppState>().set_last_synced_at(now);
+    telemetry::track(app, telemetry::TelemetryEvent::SyncCompleted);
+
+    if let Err(e) = store::save(app, LAST_SYNCED_AT_KEY, &now) {
+        eprintln!("Failed to persist last sync time: {}", e);
+    }
+}
+
+/// Restore the last successful sync timestamp from the store
+pub fn restore_last_synced_at(app: &AppHandle) {
+    if let Some(timestamp) = store::load::<DateTime<Utc>, _>(app, LAST_SYNCED_AT_KEY) {
+        app.state::<AppState>().set_last_synced_at(timestamp);
+    }
+}
+
+/// Push the whole queue right away, showing the syncing state in the tray
+pub async fn force_sync(app: &AppHandle) -> AppResult<SyncSummary> {
+    let state = app.state::<AppState>();
+    let config = state.get_config();
+    let client = SupabaseClient::from_config(&config)
+        .ok_or_else(|| AppError::invalid_config("supabase_url"))?;
+    state.sync_queue.set_rate_limit(config.sync_rate_limit_per_second);
+
+    let previous = state.get_connection_status();
+    system_tray::set_sync_in_progress(true);
+    let _ = system_tray::update_tray_icon_status(app, ConnectionStatus::Syncing);
+
+    let summary = state.sync_queue.force_flush(&client, app).await;
+    if summary.pushed > 0 {
+        record_successful_sync(app);
+    }
+
+    let _ = system_tray::update_tray_icon_status(app, previous);
+    system_tray::set_sync_in_progress(false);
+    Ok(summary)
+}
+
+/// Periodically flush the queue in the background while sync is enabled
+pub fn spawn_flush_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(FLUSH_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            interval.tick().await;
+
+            let state = app.state::<AppState>();
+            let config = state.get_config();
+            if !config.sync_enabled || state.sync_queue.depth() == 0 {
+                continue;
+            }
+
+            if let Some(client) = SupabaseClient::from_config(&config) {
+                state.sync_queue.set_rate_limit(config.sync_rate_limit_per_second);
+                let summary = state.sync_queue.flush(&client).await;
+                emit_conflicts(&app, &summary);
+                if summary.pushed > 0 {
+                    record_successful_sync(&app);
+                }
+            }
+        }
+    });
+}
@@ -1,61 +1,308 @@
+use crate::app_state::{AppConfig, AppState, HealthStatus, RaidStatus, TrayClickAction, CONFIG_STORE_KEY};
+use crate::error::AppResultExt;
+use crate::hotkeys;
+use crate::quest_tracker::{self, QuestCompletion, QuestStats};
+use crate::store;
+use crate::sync_queue;
+use crate::types::HotkeyBindings;
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use tauri::{
-    menu::{Menu, MenuItem},
+    image::Image,
+    menu::{CheckMenuItem, Menu, MenuItem, Submenu},
+    path::BaseDirectory,
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Emitter, Manager, Runtime,
+    AppHandle, Emitter, Manager, Runtime, Wry,
 };
+use tokio_util::sync::CancellationToken;
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
+/// ID of the tray icon created in `setup_system_tray`
+pub const TRAY_ID: &str = "main";
+
+/// Bundled tray icon resources, relative to the resource directory
+const TRAY_CONNECTED_ICON: &str = "icons/tray/tray_connected.ico";
+const TRAY_DISCONNECTED_ICON: &str = "icons/tray/tray_disconnected.ico";
+const TRAY_SYNCING_FRAMES: &[&str] = &[
+    "icons/tray/tray_syncing.ico",
+    "icons/tray/tray_syncing_1.ico",
+    "icons/tray/tray_syncing_2.ico",
+];
+
+/// Longest tray menu label before it is cut off with an ellipsis
+const MAX_MENU_LABEL_CHARS: usize = 30;
+
+/// Menu ID prefix for "Recent Completions" entries; the quest ID follows
+const RECENT_QUEST_ID_PREFIX: &str = "recent:";
+
+/// How long the sync result stays in the tooltip
+const SYNC_RESULT_TOOLTIP_DURATION: Duration = Duration::from_secs(5);
+
+/// "Sync Now" item of the current menu, updated while a sync runs
+static SYNC_NOW_ITEM: Mutex<Option<MenuItem<Wry>>> = Mutex::new(None);
+
+/// Whether a sync started from the tray is running
+static SYNC_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// Delay between frames of the syncing animation
+const SYNCING_FRAME_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Cancels the running syncing animation, if any
+static SYNCING_ANIMATION: Mutex<Option<CancellationToken>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ConnectionStatus {
     Connected,
+    #[default]
     Disconnected,
     Syncing,
+    Subscribed,
 }
 
-/// Setup system tray with menu items
-pub fn setup_system_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::error::Error>> {
+/// Shorten `label` to `MAX_MENU_LABEL_CHARS`, ending with an ellipsis when cut
+fn truncate_label(label: &str) -> String {
+    if label.chars().count() <= MAX_MENU_LABEL_CHARS {
+        return label.to_string();
+    }
+
+    let truncated: String = label.chars().take(MAX_MENU_LABEL_CHARS - 1).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+/// "Recent Completions" submenu, or a disabled placeholder when empty
+fn build_recent_submenu(app: &AppHandle, recent: &[QuestCompletion]) -> tauri::Result<Submenu<Wry>> {
+    let submenu = Submenu::new(app, "Recent Completions", true)?;
+
+    if recent.is_empty() {
+        submenu.append(&MenuItem::with_id(app, "recent-empty", "No completions yet", false, None::<&str>)?)?;
+    }
+
+    for quest in recent {
+        let label = if quest.trader.is_empty() {
+            quest.quest_name.clone()
+        } else {
+            format!("{} ({})", quest.quest_name, quest.trader)
+        };
+        let id = format!("{}{}", RECENT_QUEST_ID_PREFIX, quest.quest_id);
+        submenu.append(&MenuItem::with_id(app, id, truncate_label(&label), true, None::<&str>)?)?;
+    }
+
+    Ok(submenu)
+}
+
+fn sync_now_label(syncing: bool) -> &'static str {
+    if syncing {
+        "Syncing…"
+    } else {
+        "Sync Now"
+    }
+}
+
+/// "Sync Now" item reflecting whether a sync is running; kept for later updates
+fn build_sync_now_item(app: &AppHandle) -> tauri::Result<MenuItem<Wry>> {
+    let syncing = SYNC_IN_PROGRESS.load(Ordering::SeqCst);
+    let item = MenuItem::with_id(app, "sync-now", sync_now_label(syncing), !syncing, None::<&str>)?;
+    *SYNC_NOW_ITEM.lock().unwrap() = Some(item.clone());
+    Ok(item)
+}
+
+/// Relabel and enable/disable "Sync Now" around a forced sync
+pub fn set_sync_in_progress(syncing: bool) {
+    SYNC_IN_PROGRESS.store(syncing, Ordering::SeqCst);
+    if let Some(item) = SYNC_NOW_ITEM.lock().unwrap().as_ref() {
+        let _ = item.set_text(sync_now_label(syncing));
+        let _ = item.set_enabled(!syncing);
+    }
+}
+
+/// Run a forced sync from the tray, briefly showing the result in the tooltip
+fn sync_from_tray(app: &AppHandle) {
+    if SYNC_IN_PROGRESS.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let message = match sync_queue::force_sync(&app).await {
+            Ok(summary) => format!(
+                "Tarkov Quest Companion - Sync complete\n{} pushed, {} failed",
+                summary.pushed, summary.failed
+            ),
+            Err(e) => format!("Tarkov Quest Companion - Sync failed\n{}", e.message),
+        };
+
+        if let Some(tray) = app.tray_by_id(TRAY_ID) {
+            let _ = tray.set_tooltip(Some(message));
+        }
+        tokio::time::sleep(SYNC_RESULT_TOOLTIP_DURATION).await;
+        refresh_tray_tooltip(&app);
+    });
+}
+
+/// Label of the non-clickable raid indicator at the top of the menu
+fn raid_status_label(status: &RaidStatus) -> Option<String> {
+    match status {
+        RaidStatus::Idle => None,
+        RaidStatus::InRaid { map, .. } => Some(format!("⚔ In Raid: {}", map)),
+        RaidStatus::Ended { survived: true, .. } => Some("Last raid: survived ✓".to_string()),
+        RaidStatus::Ended { survived: false, .. } => Some("Last raid: died ✗".to_string()),
+    }
+}
+
+/// `label` followed by a tab and the shortcut bound to the first of `actions` that has one
+fn label_with_shortcut(label: &str, bindings: &HotkeyBindings, actions: &[&str]) -> String {
+    match actions.iter().find_map(|action| bindings.get(*action)) {
+        Some(shortcut) => format!(
+            "{}\t{}",
+            label,
+            shortcut.replace("CommandOrControl", "Ctrl").replace("CmdOrCtrl", "Ctrl")
+        ),
+        None => label.to_string(),
+    }
+}
+
+/// Build the tray menu; "Sync Now" is only shown while sync is enabled
+fn build_tray_menu(
+    app: &AppHandle,
+    config: &AppConfig,
+    bindings: &HotkeyBindings,
+    recent: &[QuestCompletion],
+) -> tauri::Result<Menu<Wry>> {
+    let menu = Menu::new(app)?;
+    if let Some(label) = raid_status_label(&app.state::<AppState>().get_raid_status()) {
+        menu.append(&MenuItem::with_id(app, "raid-status", truncate_label(&label), false, None::<&str>)?)?;
+    }
+
     // Create menu items
-    let show_item = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
+    let show_label = label_with_shortcut("Show Window", bindings, &[hotkeys::SHOW_WINDOW, hotkeys::TOGGLE_WINDOW]);
+    let show_item = MenuItem::with_id(app, "show", show_label, true, None::<&str>)?;
+    let recent_submenu = build_recent_submenu(app, recent)?;
     let settings_item = MenuItem::with_id(app, "settings", "Settings", true, None::<&str>)?;
+    let start_minimized_item =
+        CheckMenuItem::with_id(app, "start-minimized", "Start Minimized", true, config.start_minimized, None::<&str>)?;
     let import_item = MenuItem::with_id(app, "import", "Import Progress", true, None::<&str>)?;
     let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
 
-    // Build menu
-    let menu = Menu::with_items(
-        app,
-        &[&show_item, &settings_item, &import_item, &quit_item],
-    )?;
+    menu.append_items(&[&show_item, &recent_submenu, &settings_item])?;
+    if config.sync_enabled {
+        menu.append(&build_sync_now_item(app)?)?;
+    } else {
+        *SYNC_NOW_ITEM.lock().unwrap() = None;
+    }
+    menu.append_items(&[&import_item, &start_minimized_item, &quit_item])?;
+    Ok(menu)
+}
+
+/// Replace the tray menu, e.g. after a quest completion or config change
+pub fn rebuild_tray_menu(
+    app: &AppHandle,
+    config: &AppConfig,
+    recent: &[QuestCompletion],
+) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        tray.set_menu(Some(build_tray_menu(app, config, &config.hotkey_bindings, recent)?))?;
+        tray.set_show_menu_on_left_click(config.tray_click_action == TrayClickAction::ShowMenu)?;
+    }
+    Ok(())
+}
+
+/// Most recent completions for the submenu
+fn load_recent_completions(app: &AppHandle) -> Vec<QuestCompletion> {
+    app.state::<AppState>()
+        .get_recent_completions(quest_tracker::RECENT_COMPLETIONS_LIMIT)
+}
+
+/// Rebuild the tray menu from the current config and recent completions on the main thread
+pub fn refresh_tray_menu(app: &AppHandle) {
+    let handle = app.clone();
+    let result = app.run_on_main_thread(move || {
+        let config = handle.state::<AppState>().get_config();
+        let recent = load_recent_completions(&handle);
+        if let Err(e) = rebuild_tray_menu(&handle, &config, &recent) {
+            eprintln!("Failed to rebuild tray menu: {}", e);
+        }
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to schedule tray menu rebuild: {}", e);
+    }
+}
+
+/// Bring the main window to the front, optionally navigating the frontend to `route`
+fn show_main_window(app: &AppHandle, route: Option<String>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+        if let Some(route) = route {
+            let _ = window.emit("navigate", route);
+        }
+    }
+}
+
+/// Left click on the tray icon, per `AppConfig.tray_click_action`
+fn handle_tray_click(app: &AppHandle) {
+    match app.state::<AppState>().get_config().tray_click_action {
+        TrayClickAction::AlwaysShow => show_main_window(app, None),
+        TrayClickAction::Toggle => {
+            let Some(window) = app.get_webview_window("main") else {
+                return;
+            };
+            let visible = window.is_visible().unwrap_or(false);
+            let focused = window.is_focused().unwrap_or(false);
+            if visible && focused {
+                let _ = window.hide();
+            } else {
+                show_main_window(app, None);
+            }
+        }
+        // The menu itself is opened by the tray
+        TrayClickAction::ShowMenu => {}
+    }
+}
+
+/// Flip `AppConfig.start_minimized` and persist it
+fn toggle_start_minimized(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let mut config = state.get_config();
+    config.start_minimized = !config.start_minimized;
+    state.set_config(config.clone());
+    store::save(app, CONFIG_STORE_KEY, &config).log_and_ignore("Failed to persist start minimized preference");
+    refresh_tray_menu(app);
+}
+
+/// Dispatch a tray menu click
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        "show" => show_main_window(app, None),
+        "settings" => show_main_window(app, Some("/settings".to_string())),
+        "sync-now" => sync_from_tray(app),
+        "import" => show_main_window(app, Some("/import".to_string())),
+        "start-minimized" => toggle_start_minimized(app),
+        "quit" => app.exit(0),
+        id => {
+            if let Some(quest_id) = id.strip_prefix(RECENT_QUEST_ID_PREFIX) {
+                show_main_window(app, Some(format!("/quest/{}", quest_id)));
+            }
+        }
+    }
+}
+
+/// Setup system tray with menu items
+pub fn setup_system_tray(app: &AppHandle, bindings: &HotkeyBindings) -> Result<(), Box<dyn std::error::Error>> {
+    let config = app.state::<AppState>().get_config();
+    let menu = build_tray_menu(app, &config, bindings, &load_recent_completions(app))?;
+    let status = app.state::<AppState>().get_connection_status();
+    let icon = load_tray_icon(app, icon_path_for_status(&status))
+        .unwrap_or_else(|_| app.default_window_icon().unwrap().clone());
 
     // Build tray icon
-    let _tray = TrayIconBuilder::new()
+    let _tray = TrayIconBuilder::with_id(TRAY_ID)
         .menu(&menu)
-        .icon(app.default_window_icon().unwrap().clone())
+        .icon(icon)
+        .show_menu_on_left_click(config.tray_click_action == TrayClickAction::ShowMenu)
         .tooltip("Tarkov Quest Companion")
-        .on_menu_event(move |app, event| match event.id.as_ref() {
-            "show" => {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                }
-            }
-            "settings" => {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                    let _ = window.emit("navigate", "/settings");
-                }
-            }
-            "import" => {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                    let _ = window.emit("navigate", "/import");
-                }
-            }
-            "quit" => {
-                app.exit(0);
-            }
-            _ => {}
-        })
+        .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
         .on_tray_icon_event(|tray, event| {
             if let TrayIconEvent::Click {
                 button: MouseButton::Left,
@@ -63,11 +310,7 @@ pub fn setup_system_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn s
                 ..
             } = event
             {
-                let app = tray.app_handle();
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                }
+                handle_tray_click(tray.app_handle());
             }
         })
         .build(app)?;
@@ -75,26 +318,169 @@ pub fn setup_system_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn s
     Ok(())
 }
 
+/// Icon resource shown for a connection status
+pub fn icon_path_for_status(status: &ConnectionStatus) -> &'static str {
+    match status {
+        ConnectionStatus::Connected | ConnectionStatus::Subscribed => TRAY_CONNECTED_ICON,
+        ConnectionStatus::Disconnected => TRAY_DISCONNECTED_ICON,
+        ConnectionStatus::Syncing => TRAY_SYNCING_FRAMES[0],
+    }
+}
+
+/// Load a bundled tray icon from the resource directory
+fn load_tray_icon<R: Runtime>(app: &AppHandle<R>, resource: &str) -> Result<Image<'static>, Box<dyn std::error::Error>> {
+    let path = app.path().resolve(resource, BaseDirectory::Resource)?;
+    Ok(Image::from_path(path)?)
+}
+
+/// Cycle the syncing frames until the next status change
+fn start_syncing_animation<R: Runtime>(app: &AppHandle<R>) {
+    let cancel = CancellationToken::new();
+    if let Some(previous) = SYNCING_ANIMATION.lock().unwrap().replace(cancel.clone()) {
+        previous.cancel();
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut frames = TRAY_SYNCING_FRAMES.iter().cycle().skip(1);
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(SYNCING_FRAME_INTERVAL) => {
+                    let (Some(tray), Some(frame)) = (app.tray_by_id(TRAY_ID), frames.next()) else {
+                        break;
+                    };
+                    if let Ok(icon) = load_tray_icon(&app, frame) {
+                        let _ = tray.set_icon(Some(icon));
+                    }
+                }
+            }
+        }
+    });
+}
+
+fn stop_syncing_animation() {
+    if let Some(cancel) = SYNCING_ANIMATION.lock().unwrap().take() {
+        cancel.cancel();
+    }
+}
+
 /// Update tray icon based on connection status
 pub fn update_tray_icon_status<R: Runtime>(
     app: &AppHandle<R>,
     status: ConnectionStatus,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    // Re-applying the same icon makes the tray flicker
+    let state = app.state::<AppState>();
+    if state.get_connection_status() == status {
+        return Ok(());
+    }
+    state.set_connection_status(status.clone());
+
     // Get the tray icon
-    if let Some(tray) = app.tray_by_id("main") {
-        // Update tooltip based on status
-        let tooltip = match status {
-            ConnectionStatus::Connected => "Tarkov Quest Companion - Connected",
-            ConnectionStatus::Disconnected => "Tarkov Quest Companion - Disconnected",
-            ConnectionStatus::Syncing => "Tarkov Quest Companion - Syncing...",
-        };
-        
-        tray.set_tooltip(Some(tooltip))?;
-        
-        // Note: To change icon color, you would need different icon files
-        // For now, we update the tooltip to indicate status
-        // Future enhancement: Load different icon files based on status
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        tray.set_tooltip(Some(build_tooltip(app, &status)))?;
+
+        stop_syncing_animation();
+        tray.set_icon(Some(load_tray_icon(app, icon_path_for_status(&status))?))?;
+        if matches!(status, ConnectionStatus::Syncing) {
+            start_syncing_animation(app);
+        }
     }
 
     Ok(())
 }
+
+/// Short label for a connection status
+fn status_label(status: &ConnectionStatus) -> &'static str {
+    match status {
+        ConnectionStatus::Connected => "Connected",
+        ConnectionStatus::Disconnected => "Disconnected",
+        ConnectionStatus::Syncing => "Syncing...",
+        ConnectionStatus::Subscribed => "Live sync active",
+    }
+}
+
+/// Tray tooltip text, e.g. "Tarkov Quest Companion — 47/273 quests (17%)"
+pub fn format_tray_tooltip(status: &ConnectionStatus, stats: &QuestStats) -> String {
+    if stats.total == 0 {
+        return format!("Tarkov Quest Companion - {}", status_label(status));
+    }
+
+    format!(
+        "Tarkov Quest Companion — {}/{} quests ({}%)\n{}",
+        stats.completed,
+        stats.total,
+        stats.percent(),
+        status_label(status)
+    )
+}
+
+/// Tooltip for the current quest stats, with the last sync time while connected and a warning when unhealthy
+fn build_tooltip<R: Runtime>(app: &AppHandle<R>, status: &ConnectionStatus) -> String {
+    let state = app.state::<AppState>();
+    let tooltip = format_tray_tooltip(status, &state.get_quest_stats());
+
+    let tooltip = match (status, state.get_last_synced_at()) {
+        (ConnectionStatus::Connected, Some(last_synced)) => {
+            format!("{}\nLast synced: {}", tooltip, format_time_ago(last_synced))
+        }
+        _ => tooltip,
+    };
+
+    if state.health_check().status == HealthStatus::Healthy {
+        tooltip
+    } else {
+        format!("{}\n⚠ Degraded", tooltip)
+    }
+}
+
+/// Re-render the tooltip for the current status, e.g. after quest stats change
+pub fn refresh_tray_tooltip<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let status = app.state::<AppState>().get_connection_status();
+        let _ = tray.set_tooltip(Some(build_tooltip(app, &status)));
+    }
+}
+
+/// Format a past timestamp as a short relative string ("3 min ago")
+fn format_time_ago(timestamp: DateTime<Utc>) -> String {
+    let elapsed = Utc::now().signed_duration_since(timestamp);
+
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{} min ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{} h ago", elapsed.num_hours())
+    } else {
+        format!("{} d ago", elapsed.num_days())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_connection_status_maps_to_its_icon() {
+        assert_eq!(icon_path_for_status(&ConnectionStatus::Connected), TRAY_CONNECTED_ICON);
+        assert_eq!(icon_path_for_status(&ConnectionStatus::Subscribed), TRAY_CONNECTED_ICON);
+        assert_eq!(icon_path_for_status(&ConnectionStatus::Disconnected), TRAY_DISCONNECTED_ICON);
+        assert_eq!(icon_path_for_status(&ConnectionStatus::Syncing), TRAY_SYNCING_FRAMES[0]);
+    }
+
+    #[test]
+    fn status_icons_exist_on_disk() {
+        let statuses = [
+            ConnectionStatus::Connected,
+            ConnectionStatus::Disconnected,
+            ConnectionStatus::Syncing,
+            ConnectionStatus::Subscribed,
+        ];
+        for status in &statuses {
+            let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(icon_path_for_status(status));
+            assert!(path.is_file(), "missing tray icon {}", path.display());
+        }
+    }
+}
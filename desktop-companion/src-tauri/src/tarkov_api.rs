@@ -0,0 +1,135 @@
+use crate::error::{AppError, AppResult};
+use crate::store;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tauri::{AppHandle, Runtime};
+
+/// tarkov.dev GraphQL endpoint
+const API_URL: &str = "https://api.tarkov.dev/graphql";
+
+/// Store key for cached quest metadata
+const CACHE_KEY: &str = "quest_meta_cache";
+
+/// How long cached metadata stays fresh
+const CACHE_TTL_HOURS: i64 = 24;
+
+const QUEST_QUERY: &str = "query Task($id: ID!) { task(id: $id) { id name trader { name } objectives { description } map { name } wikiLink } }";
+
+const QUEST_COUNT_QUERY: &str = "query { tasks { id } }";
+
+/// Display metadata for a quest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestMeta {
+    pub id: String,
+    pub name: String,
+    pub trader: String,
+    pub objectives: Vec<String>,
+    pub map: Option<String>,
+    pub wiki_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedQuestMeta {
+    meta: QuestMeta,
+    fetched_at: DateTime<Utc>,
+}
+
+/// Fetch quest metadata from tarkov.dev
+pub async fn fetch_quest_by_id(quest_id: &str) -> AppResult<QuestMeta> {
+    let response = reqwest::Client::new()
+        .post(API_URL)
+        .json(&json!({ "query": QUEST_QUERY, "variables": { "id": quest_id } }))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(AppError::network_error(format!(
+            "tarkov.dev responded with HTTP {}",
+            response.status().as_u16()
+        )));
+    }
+
+    let body: Value = response.json().await?;
+    if let Some(errors) = body.get("errors") {
+        return Err(AppError::parse_error(errors));
+    }
+
+    let task = &body["data"]["task"];
+    if task.is_null() {
+        return Err(AppError::quest_not_found(quest_id));
+    }
+
+    Ok(QuestMeta {
+        id: task["id"].as_str().unwrap_or(quest_id).to_string(),
+        name: task["name"].as_str().unwrap_or_default().to_string(),
+        trader: task["trader"]["name"].as_str().unwrap_or_default().to_string(),
+        objectives: task["objectives"]
+            .as_array()
+            .map(|objectives| {
+                objectives
+                    .iter()
+                    .filter_map(|o| o["description"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        map: task["map"]["name"].as_str().map(str::to_string),
+        wiki_url: task["wikiLink"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+/// Number of quests currently in the game, according to tarkov.dev
+pub async fn fetch_quest_count() -> AppResult<u32> {
+    let response = reqwest::Client::new()
+        .post(API_URL)
+        .json(&json!({ "query": QUEST_COUNT_QUERY }))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(AppError::network_error(format!(
+            "tarkov.dev responded with HTTP {}",
+            response.status().as_u16()
+        )));
+    }
+
+    let body: Value = response.json().await?;
+    if let Some(errors) = body.get("errors") {
+        return Err(AppError::parse_error(errors));
+    }
+
+    body["data"]["tasks"]
+        .as_array()
+        .map(|tasks| tasks.len() as u32)
+        .ok_or_else(|| AppError::parse_error("missing tasks list"))
+}
+
+/// Every cached quest's metadata, regardless of age (no network access)
+pub fn cached_quest_meta<R: Runtime>(app: &AppHandle<R>) -> Vec<QuestMeta> {
+    let cache: HashMap<String, CachedQuestMeta> = store::load(app, CACHE_KEY).unwrap_or_default();
+    cache.into_values().map(|cached| cached.meta).collect()
+}
+
+/// Return quest metadata from the cache, fetching and caching it when missing or stale
+pub async fn get_quest_meta(app: &AppHandle, quest_id: &str) -> AppResult<QuestMeta> {
+    let mut cache: HashMap<String, CachedQuestMeta> = store::load(app, CACHE_KEY).unwrap_or_default();
+
+    if let Some(cached) = cache.get(quest_id) {
+        if Utc::now() - cached.fetched_at < Duration::hours(CACHE_TTL_HOURS) {
+            return Ok(cached.meta.clone());
+        }
+    }
+
+    let meta = fetch_quest_by_id(quest_id).await?;
+    cache.insert(
+        quest_id.to_string(),
+        CachedQuestMeta {
+            meta: meta.clone(),
+            fetched_at: Utc::now(),
+        },
+    );
+    store::save(app, CACHE_KEY, &cache)?;
+
+    Ok(meta)
+}
@@ -1,109 +1,917 @@
-use std::path::PathBuf;
-use winreg::enums::*;
-use winreg::RegKey;
+use crate::log_watcher;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tokio_util::sync::CancellationToken;
+
+/// Registry root a detection source reads from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistryHive {
+    LocalMachine,
+    CurrentUser,
+}
+
+/// Shell folder that may be redirected away from its environment-variable location
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnownFolder {
+    RoamingAppData,
+    Documents,
+}
+
+/// Registry and filesystem access used by installation detection, replaceable in tests
+pub trait PathResolver {
+    /// String value `name` under `key`, if present
+    fn registry_value(&self, hive: RegistryHive, key: &str, name: &str) -> Option<String>;
+    fn exists(&self, path: &Path) -> bool;
+    fn read_to_string(&self, path: &Path) -> Option<String>;
+    fn env_var(&self, name: &str) -> Option<String>;
+    /// Location the shell reports for `folder`, which follows folder redirection
+    fn known_folder(&self, folder: KnownFolder) -> Option<PathBuf>;
+}
+
+/// The real registry and filesystem
+pub struct SystemPathResolver;
+
+impl PathResolver for SystemPathResolver {
+    #[cfg(windows)]
+    fn registry_value(&self, hive: RegistryHive, key: &str, name: &str) -> Option<String> {
+        use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+        use winreg::RegKey;
+
+        let root = match hive {
+            RegistryHive::LocalMachine => RegKey::predef(HKEY_LOCAL_MACHINE),
+            RegistryHive::CurrentUser => RegKey::predef(HKEY_CURRENT_USER),
+        };
+        root.open_subkey(key).ok()?.get_value::<String, _>(name).ok()
+    }
+
+    /// There is no registry outside Windows
+    #[cfg(not(windows))]
+    fn registry_value(&self, _hive: RegistryHive, _key: &str, _name: &str) -> Option<String> {
+        None
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn read_to_string(&self, path: &Path) -> Option<String> {
+        std::fs::read_to_string(path).ok()
+    }
+
+    fn env_var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+
+    #[cfg(windows)]
+    fn known_folder(&self, folder: KnownFolder) -> Option<PathBuf> {
+        use windows::Win32::Foundation::HANDLE;
+        use windows::Win32::System::Com::CoTaskMemFree;
+        use windows::Win32::UI::Shell::{FOLDERID_Documents, FOLDERID_RoamingAppData, SHGetKnownFolderPath, KF_FLAG_DEFAULT};
+
+        let id = match folder {
+            KnownFolder::RoamingAppData => &FOLDERID_RoamingAppData,
+            KnownFolder::Documents => &FOLDERID_Documents,
+        };
+        unsafe {
+            let raw = SHGetKnownFolderPath(id, KF_FLAG_DEFAULT, HANDLE::default()).ok()?;
+            let path = raw.to_string().ok();
+            CoTaskMemFree(Some(raw.as_ptr() as *const _));
+            path.map(PathBuf::from)
+        }
+    }
+
+    /// There is no shell folder redirection outside Windows
+    #[cfg(not(windows))]
+    fn known_folder(&self, _folder: KnownFolder) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// How a Tarkov installation was found
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionSource {
+    Registry,
+    Steam,
+    CommonPath,
+    RunningProcess,
+}
+
+impl DetectionSource {
+    /// Hint shown next to the detected path
+    pub fn label(&self) -> &'static str {
+        match self {
+            DetectionSource::Registry => "Detected via launcher registry entry",
+            DetectionSource::Steam => "Detected via Steam library",
+            DetectionSource::CommonPath => "Detected in a common install location",
+            DetectionSource::RunningProcess => "Detected via running process",
+        }
+    }
+}
+
+/// Detected log directory and where it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct TarkovInstallInfo {
+    pub log_directory: String,
+    pub detected_via: DetectionSource,
+    pub detected_via_label: String,
+}
+
+/// How long a successful detection is reused before probing again
+const DETECTION_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Last successful detection and when it happened
+static DETECTION_CACHE: Mutex<Option<(Instant, TarkovInstallInfo)>> = Mutex::new(None);
+
+/// Forget the cached detection so the next call probes again
+pub fn bust_detection_cache() {
+    *DETECTION_CACHE.lock().unwrap() = None;
+}
+
+/// Auto-detect the Tarkov installation, reusing a recent successful result
+pub fn detect_tarkov_installation() -> Result<TarkovInstallInfo, String> {
+    {
+        let cache = DETECTION_CACHE.lock().unwrap();
+        if let Some((detected_at, info)) = cache.as_ref() {
+            // A moved installation invalidates the cache even before it expires
+            if detected_at.elapsed() < DETECTION_CACHE_TTL && Path::new(&info.log_directory).exists() {
+                return Ok(info.clone());
+            }
+        }
+    }
+
+    let info = probe_tarkov_installation(&SystemPathResolver)?;
+    *DETECTION_CACHE.lock().unwrap() = Some((Instant::now(), info.clone()));
+    Ok(info)
+}
+
+/// Probe each detection source in priority order
+fn probe_tarkov_installation(resolver: &impl PathResolver) -> Result<TarkovInstallInfo, String> {
+    let found = |log_directory: String, detected_via: DetectionSource| TarkovInstallInfo {
+        log_directory,
+        detected_via,
+        detected_via_label: detected_via.label().to_string(),
+    };
 
-/// Auto-detect Tarkov installation directory
-pub fn detect_tarkov_directory() -> Result<String, String> {
     // Try Windows Registry first (EFT Launcher)
-    if let Ok(path) = detect_from_registry() {
-        return Ok(path);
+    if let Ok(path) = detect_from_registry(resolver) {
+        return Ok(found(path, DetectionSource::Registry));
     }
 
     // Try Steam installation
-    if let Ok(path) = detect_from_steam() {
-        return Ok(path);
+    if let Ok(path) = detect_from_steam(resolver) {
+        return Ok(found(path, DetectionSource::Steam));
     }
 
     // Try common paths
-    detect_from_common_paths()
+    if let Ok(path) = detect_from_common_paths(resolver) {
+        return Ok(found(path, DetectionSource::CommonPath));
+    }
+
+    // Last resort: ask a running game client where it lives
+    #[cfg(target_os = "windows")]
+    if let Ok(path) = detect_from_running_process() {
+        return Ok(found(path, DetectionSource::RunningProcess));
+    }
+
+    Err("Tarkov installation not found".to_string())
 }
 
-/// Detect from Windows Registry (EFT Launcher installation)
-fn detect_from_registry() -> Result<String, String> {
-    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
-    
-    // Try EFT registry key
-    if let Ok(eft_key) = hklm.open_subkey("SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\EscapeFromTarkov") {
-        if let Ok(install_location) = eft_key.get_value::<String, _>("InstallLocation") {
-            let log_path = PathBuf::from(install_location).join("Logs");
-            if log_path.exists() {
-                return Ok(log_path.to_string_lossy().to_string());
+/// A detected installation ranked against the others
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedInstallInfo {
+    #[serde(flatten)]
+    pub install: TarkovInstallInfo,
+    /// 0–100, higher means more likely to be the installation in use
+    pub confidence_score: u8,
+}
+
+/// Most recent modification time of any file in `dir` or its direct subdirectories
+fn newest_log_modification(dir: &Path) -> Option<std::time::SystemTime> {
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .flat_map(|path| {
+            if path.is_dir() {
+                std::fs::read_dir(&path)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter_map(|entry| modified(&entry.path()))
+                    .max()
+            } else {
+                modified(&path)
+            }
+        })
+        .max()
+}
+
+/// Score an installation by how recently the game wrote to its logs
+fn confidence_score(install: &TarkovInstallInfo) -> u8 {
+    // The game is running from here right now
+    if install.detected_via == DetectionSource::RunningProcess {
+        return 100;
+    }
+
+    const DAY: u64 = 24 * 60 * 60;
+    let age = newest_log_modification(Path::new(&install.log_directory))
+        .and_then(|modified| modified.elapsed().ok())
+        .map(|age| age.as_secs());
+
+    match age {
+        Some(secs) if secs < DAY => 90,
+        Some(secs) if secs < 7 * DAY => 70,
+        Some(secs) if secs < 30 * DAY => 50,
+        Some(_) => 30,
+        None => 10,
+    }
+}
+
+/// Every installation found by any source, most likely first
+pub fn detect_all_tarkov_directories() -> Vec<RankedInstallInfo> {
+    let resolver = SystemPathResolver;
+    let mut candidates: Vec<(String, DetectionSource)> = Vec::new();
+    candidates.extend(detect_from_registry(&resolver).ok().map(|path| (path, DetectionSource::Registry)));
+    candidates.extend(steam_candidates(&resolver).into_iter().map(|path| (path, DetectionSource::Steam)));
+    candidates.extend(common_path_candidates(&resolver).into_iter().map(|path| (path, DetectionSource::CommonPath)));
+    #[cfg(target_os = "windows")]
+    candidates.extend(detect_from_running_process().ok().map(|path| (path, DetectionSource::RunningProcess)));
+
+    let mut ranked: Vec<RankedInstallInfo> = Vec::new();
+    for (log_directory, detected_via) in candidates {
+        let physical = resolve_physical_path(Path::new(&log_directory));
+        let install = TarkovInstallInfo {
+            log_directory,
+            detected_via,
+            detected_via_label: detected_via.label().to_string(),
+        };
+        let confidence_score = confidence_score(&install);
+
+        // The same directory can be found by several sources; keep the best-scored one
+        match ranked
+            .iter_mut()
+            .find(|existing| resolve_physical_path(Path::new(&existing.install.log_directory)) == physical)
+        {
+            Some(existing) if existing.confidence_score < confidence_score => {
+                *existing = RankedInstallInfo { install, confidence_score };
             }
+            Some(_) => {}
+            None => ranked.push(RankedInstallInfo { install, confidence_score }),
         }
     }
 
-    Err("Registry key not found".to_string())
+    // Stable sort keeps detection priority order between equal scores
+    ranked.sort_by_key(|install| std::cmp::Reverse(install.confidence_score));
+    ranked
+}
+
+/// Auto-detect Tarkov installation directory
+pub fn detect_tarkov_directory() -> Result<String, String> {
+    detect_tarkov_installation().map(|info| info.log_directory)
+}
+
+/// How often `watch_for_installation` retries detection
+const INSTALLATION_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Retry detection until the game shows up, then emit `tarkov-installation-found` once
+pub async fn watch_for_installation(app: AppHandle, cancel: CancellationToken) {
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => return,
+            _ = tokio::time::sleep(INSTALLATION_POLL_INTERVAL) => {}
+        }
+
+        let detected = tauri::async_runtime::spawn_blocking(detect_tarkov_installation).await;
+        if let Ok(Ok(info)) = detected {
+            let _ = app.emit("tarkov-installation-found", info);
+            return;
+        }
+    }
+}
+
+/// Uninstall entry written by the BSG Launcher
+const LAUNCHER_UNINSTALL_KEY: &str =
+    "SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall\\EscapeFromTarkov";
+
+/// Detect from Windows Registry (EFT Launcher installation)
+fn detect_from_registry(resolver: &impl PathResolver) -> Result<String, String> {
+    let install_location = resolver
+        .registry_value(RegistryHive::LocalMachine, LAUNCHER_UNINSTALL_KEY, "InstallLocation")
+        .ok_or_else(|| "Registry key not found".to_string())?;
+
+    let log_path = PathBuf::from(install_location).join("Logs");
+    if resolver.exists(&log_path) {
+        Ok(log_path.to_string_lossy().to_string())
+    } else {
+        Err("Registry key not found".to_string())
+    }
 }
 
 /// Detect from Steam library folders
-fn detect_from_steam() -> Result<String, String> {
-    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
-    
-    // Get Steam installation path
-    if let Ok(steam_key) = hkcu.open_subkey("SOFTWARE\\Valve\\Steam") {
-        if let Ok(steam_path) = steam_key.get_value::<String, _>("SteamPath") {
-            // Check common library folders
-            let library_folders = vec![
-                PathBuf::from(&steam_path).join("steamapps\\common\\Escape from Tarkov\\Logs"),
-                PathBuf::from("C:\\Program Files (x86)\\Steam\\steamapps\\common\\Escape from Tarkov\\Logs"),
-                PathBuf::from("D:\\Steam\\steamapps\\common\\Escape from Tarkov\\Logs"),
-            ];
-
-            for folder in library_folders {
-                if folder.exists() {
-                    return Ok(folder.to_string_lossy().to_string());
-                }
+fn detect_from_steam(resolver: &impl PathResolver) -> Result<String, String> {
+    steam_candidates(resolver)
+        .into_iter()
+        .next()
+        .ok_or_else(|| "Steam installation not found".to_string())
+}
+
+/// Steam libraries checked when `libraryfolders.vdf` doesn't list the game
+const FALLBACK_STEAM_LIBRARIES: [&str; 2] = ["C:\\Program Files (x86)\\Steam", "D:\\Steam"];
+
+/// `Logs` directory of the game inside a Steam library
+fn steam_library_logs(library: &Path) -> PathBuf {
+    library.join("steamapps\\common\\Escape from Tarkov\\Logs")
+}
+
+/// Library paths listed in Steam's `libraryfolders.vdf`
+fn parse_library_folders(vdf: &str) -> Vec<PathBuf> {
+    vdf.lines()
+        .filter_map(|line| {
+            // `"path"		"D:\\SteamLibrary"`
+            let mut quoted = line.split('"').skip(1).step_by(2);
+            if quoted.next()? != "path" {
+                return None;
             }
-        }
+            Some(PathBuf::from(quoted.next()?.replace("\\\\", "\\")))
+        })
+        .collect()
+}
+
+/// Every Steam library `Logs` directory that exists
+fn steam_candidates(resolver: &impl PathResolver) -> Vec<String> {
+    // Get Steam installation path
+    let Some(steam_path) = resolver.registry_value(RegistryHive::CurrentUser, "SOFTWARE\\Valve\\Steam", "SteamPath")
+    else {
+        return Vec::new();
+    };
+    let steam_path = PathBuf::from(steam_path);
+
+    // The Steam install itself, every library it knows about, then common library folders
+    let mut libraries = vec![steam_path.clone()];
+    if let Some(vdf) = resolver.read_to_string(&steam_path.join("steamapps\\libraryfolders.vdf")) {
+        libraries.extend(parse_library_folders(&vdf));
     }
+    libraries.extend(FALLBACK_STEAM_LIBRARIES.iter().map(PathBuf::from));
 
-    Err("Steam installation not found".to_string())
+    let mut candidates: Vec<String> = Vec::new();
+    for folder in libraries.iter().map(|library| steam_library_logs(library)) {
+        let folder = folder.to_string_lossy().to_string();
+        if resolver.exists(Path::new(&folder)) && !candidates.contains(&folder) {
+            candidates.push(folder);
+        }
+    }
+    candidates
 }
 
 /// Check common installation paths
-fn detect_from_common_paths() -> Result<String, String> {
-    let common_paths = vec![
-        "C:\\Battlestate Games\\Escape from Tarkov\\Logs",
-        "C:\\Battlestate Games\\EFT\\Logs",
-        "D:\\Battlestate Games\\Escape from Tarkov\\Logs",
-        "D:\\Games\\Escape from Tarkov\\Logs",
-        "E:\\Battlestate Games\\Escape from Tarkov\\Logs",
-    ];
+fn detect_from_common_paths(resolver: &impl PathResolver) -> Result<String, String> {
+    common_path_candidates(resolver)
+        .into_iter()
+        .next()
+        .ok_or_else(|| "Tarkov installation not found in common paths".to_string())
+}
 
-    for path in common_paths {
-        let path_buf = PathBuf::from(path);
-        if path_buf.exists() {
-            return Ok(path_buf.to_string_lossy().to_string());
-        }
+/// Common install locations, in the order they are tried
+const COMMON_PATHS: [&str; 5] = [
+    "C:\\Battlestate Games\\Escape from Tarkov\\Logs",
+    "C:\\Battlestate Games\\EFT\\Logs",
+    "D:\\Battlestate Games\\Escape from Tarkov\\Logs",
+    "D:\\Games\\Escape from Tarkov\\Logs",
+    "E:\\Battlestate Games\\Escape from Tarkov\\Logs",
+];
+
+/// Every common install location that exists
+fn common_path_candidates(resolver: &impl PathResolver) -> Vec<String> {
+    COMMON_PATHS
+        .into_iter()
+        .map(PathBuf::from)
+        .filter(|path_buf| resolver.exists(path_buf))
+        .map(|path_buf| path_buf.to_string_lossy().to_string())
+        .collect()
+}
+
+/// Find the `Logs` directory next to a running game executable
+#[cfg(target_os = "windows")]
+fn detect_from_running_process() -> Result<String, String> {
+    use crate::game_process::GAME_PROCESS_NAMES;
+    use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+
+    system
+        .processes()
+        .values()
+        .filter(|process| {
+            let name = process.name().to_string_lossy();
+            GAME_PROCESS_NAMES.iter().any(|game| name.eq_ignore_ascii_case(game))
+        })
+        .filter_map(|process| process.exe()?.parent().map(|root| root.join("Logs")))
+        .find(|log_path| log_path.exists())
+        .map(|log_path| log_path.to_string_lossy().to_string())
+        .ok_or_else(|| "No running game process found".to_string())
+}
+
+/// Drop the `\\?\` prefix `canonicalize` adds on Windows so paths stay readable
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    match path.to_str().and_then(|p| p.strip_prefix(r"\\?\")) {
+        Some(stripped) if !stripped.starts_with("UNC") => PathBuf::from(stripped),
+        _ => path,
+    }
+}
+
+/// Where the environment says `folder` lives, before any redirection
+fn env_folder(resolver: &impl PathResolver, folder: KnownFolder) -> Option<PathBuf> {
+    match folder {
+        KnownFolder::RoamingAppData => resolver.env_var("APPDATA").map(PathBuf::from),
+        KnownFolder::Documents => resolver
+            .env_var("USERPROFILE")
+            .map(|profile| PathBuf::from(profile).join("Documents")),
+    }
+}
+
+/// Resolve a path under a redirected (e.g. OneDrive) `%APPDATA%` or Documents folder to its physical location.
+///
+/// Paths built from environment variables are rebased onto the shell's known
+/// folder, then symlinks and junctions are resolved when the path exists.
+pub fn resolve_physical_path(path: &Path) -> PathBuf {
+    resolve_physical_path_with(&SystemPathResolver, path)
+}
+
+fn resolve_physical_path_with(resolver: &impl PathResolver, path: &Path) -> PathBuf {
+    let rebased = [KnownFolder::RoamingAppData, KnownFolder::Documents]
+        .into_iter()
+        .find_map(|folder| {
+            let rest = path.strip_prefix(env_folder(resolver, folder)?).ok()?;
+            Some(resolver.known_folder(folder)?.join(rest))
+        });
+    let resolved = rebased.unwrap_or_else(|| path.to_path_buf());
+
+    std::fs::canonicalize(&resolved)
+        .map(strip_verbatim_prefix)
+        .unwrap_or(resolved)
+}
+
+/// Physical roaming AppData directory
+pub fn appdata_dir() -> Option<PathBuf> {
+    let resolver = SystemPathResolver;
+    resolver
+        .known_folder(KnownFolder::RoamingAppData)
+        .or_else(|| env_folder(&resolver, KnownFolder::RoamingAppData))
+        .map(|dir| resolve_physical_path(&dir))
+}
+
+/// Account profile saved by the BSG Launcher
+#[derive(Debug, Clone, Serialize)]
+pub struct BsgProfile {
+    pub username: String,
+    pub account_id: String,
+    pub profile_path: PathBuf,
+}
+
+/// Locate the BSG Launcher profile directory under `%APPDATA%`
+pub fn detect_bsg_profile_dir() -> Result<PathBuf, String> {
+    let appdata = appdata_dir().ok_or_else(|| "APPDATA is not set".to_string())?;
+    let profile_dir = appdata.join("Battlestate Games\\BsgLauncher\\profiles");
+
+    if profile_dir.is_dir() {
+        Ok(profile_dir)
+    } else {
+        Err("BSG Launcher profile directory not found".to_string())
     }
+}
 
-    Err("Tarkov installation not found in common paths".to_string())
+/// First string (or number) value found under any of `keys`
+fn first_string_field(json: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| match json.get(*key)? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+/// Parse a single launcher profile file
+fn parse_bsg_profile(path: &Path) -> Result<BsgProfile, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let json: Value = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+
+    let account_id = first_string_field(&json, &["accountId", "aid", "id"])
+        .ok_or_else(|| format!("No account ID in {}", path.display()))?;
+    let username = first_string_field(&json, &["username", "login", "nickname"]).unwrap_or_default();
+
+    Ok(BsgProfile {
+        username,
+        account_id,
+        profile_path: path.to_path_buf(),
+    })
+}
+
+/// Read every profile JSON file in the BSG Launcher profile directory
+pub fn list_bsg_profiles() -> Result<Vec<BsgProfile>, String> {
+    let profile_dir = detect_bsg_profile_dir()?;
+    let entries = std::fs::read_dir(&profile_dir).map_err(|e| e.to_string())?;
+
+    let profiles = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| match parse_bsg_profile(&path) {
+            Ok(profile) => Some(profile),
+            Err(e) => {
+                eprintln!("Skipping BSG profile {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect();
+
+    Ok(profiles)
+}
+
+/// Subdirectory count above which recursive watching gets a warning
+const RECURSIVE_SUBDIRECTORY_WARNING: usize = 50;
+
+/// Outcome of `validate_log_directory`
+#[derive(Debug, Clone, Serialize)]
+pub struct LogDirectoryValidation {
+    pub valid: bool,
+    pub warnings: Vec<String>,
 }
 
 /// Validate that a directory contains Tarkov log files
-pub fn validate_log_directory(path: &str) -> Result<bool, String> {
+pub fn validate_log_directory(path: &str, recursive: bool) -> Result<LogDirectoryValidation, String> {
     let path_buf = PathBuf::from(path);
-    
+    let mut result = LogDirectoryValidation {
+        valid: false,
+        warnings: Vec::new(),
+    };
+
     if !path_buf.exists() {
-        return Ok(false);
+        return Ok(result);
     }
 
     if !path_buf.is_dir() {
-        return Ok(false);
+        return Ok(result);
     }
 
     // Check if directory contains log subdirectories or log files
+    let mut subdirectories = 0;
     if let Ok(entries) = std::fs::read_dir(&path_buf) {
         for entry in entries.flatten() {
             let file_name = entry.file_name();
             let name = file_name.to_string_lossy();
             
+            if entry.path().is_dir() {
+                subdirectories += 1;
+            }
+
             // Look for log subdirectories or .log files
             if name.starts_with("log_") || name.ends_with(".log") {
-                return Ok(true);
+                result.valid = true;
             }
         }
     }
 
-    Ok(false)
+    if recursive && subdirectories > RECURSIVE_SUBDIRECTORY_WARNING {
+        result.warnings.push(format!(
+            "Recursive watching is enabled and this directory has {} subdirectories; consider narrowing the file patterns",
+            subdirectories
+        ));
+    }
+
+    Ok(result)
+}
+
+/// Log files untouched for longer than this are rejected as stale
+const STALE_LOG_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Log files untouched for longer than this get a warning
+const QUIET_LOG_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Prefix of every EFT log line: `2024-05-01 18:23:45.123 +02:00|0.14.6.0.29862|Info|...`
+const EFT_LOG_LINE_PATTERN: &str = r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3}[^|]*\|";
+
+/// Outcome of `validate_log_file`
+#[derive(Debug, Clone, Serialize)]
+pub struct LogFileValidation {
+    pub valid: bool,
+    pub warnings: Vec<String>,
+    pub line_count: u64,
+}
+
+/// Check that a single file looks like a current EFT log
+pub fn validate_log_file(path: &str) -> Result<LogFileValidation, String> {
+    let path = Path::new(path);
+    let mut result = LogFileValidation {
+        valid: false,
+        warnings: Vec::new(),
+        line_count: 0,
+    };
+
+    if !path.is_file() {
+        result.warnings.push("File does not exist".to_string());
+        return Ok(result);
+    }
+    if path.extension().is_none_or(|ext| ext != "log") {
+        result.warnings.push("File does not have a .log extension".to_string());
+        return Ok(result);
+    }
+
+    let content = match log_watcher::read_file_as_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            result.warnings.push(format!("File is not readable: {}", e));
+            return Ok(result);
+        }
+    };
+
+    let mut stale = false;
+    if let Some(age) = std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+    {
+        if age > STALE_LOG_AGE {
+            stale = true;
+            result.warnings.push("File not modified in 7 days".to_string());
+        } else if age > QUIET_LOG_AGE {
+            result.warnings.push("File not modified in 24h".to_string());
+        }
+    }
+
+    let line_pattern = regex::Regex::new(EFT_LOG_LINE_PATTERN).map_err(|e| e.to_string())?;
+    let mut recognised = false;
+    for line in content.lines() {
+        result.line_count += 1;
+        recognised |= line_pattern.is_match(line);
+    }
+    if !recognised {
+        result.warnings.push("No lines in the EFT log format".to_string());
+    }
+
+    result.valid = !stale && recognised;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+    use tempfile::TempDir;
+
+    /// In-memory registry and filesystem
+    #[derive(Default)]
+    struct MockPathResolver {
+        registry: HashMap<(RegistryHive, String, String), String>,
+        existing: HashSet<PathBuf>,
+        files: HashMap<PathBuf, String>,
+        env: HashMap<String, String>,
+        known_folders: HashMap<KnownFolder, PathBuf>,
+    }
+
+    impl MockPathResolver {
+        fn with_registry(mut self, hive: RegistryHive, key: &str, name: &str, value: &str) -> Self {
+            self.registry
+                .insert((hive, key.to_string(), name.to_string()), value.to_string());
+            self
+        }
+
+        fn with_dir(mut self, path: impl Into<PathBuf>) -> Self {
+            self.existing.insert(path.into());
+            self
+        }
+
+        fn with_file(mut self, path: impl Into<PathBuf>, content: &str) -> Self {
+            let path = path.into();
+            self.existing.insert(path.clone());
+            self.files.insert(path, content.to_string());
+            self
+        }
+
+        fn with_launcher_install(self, install_location: &str) -> Self {
+            self.with_registry(RegistryHive::LocalMachine, LAUNCHER_UNINSTALL_KEY, "InstallLocation", install_location)
+                .with_dir(PathBuf::from(install_location).join("Logs"))
+        }
+
+        fn with_steam(self, steam_path: &str) -> Self {
+            self.with_registry(RegistryHive::CurrentUser, "SOFTWARE\\Valve\\Steam", "SteamPath", steam_path)
+        }
+
+        fn with_env(mut self, name: &str, value: &str) -> Self {
+            self.env.insert(name.to_string(), value.to_string());
+            self
+        }
+
+        fn with_known_folder(mut self, folder: KnownFolder, path: impl Into<PathBuf>) -> Self {
+            self.known_folders.insert(folder, path.into());
+            self
+        }
+    }
+
+    impl PathResolver for MockPathResolver {
+        fn registry_value(&self, hive: RegistryHive, key: &str, name: &str) -> Option<String> {
+            self.registry
+                .get(&(hive, key.to_string(), name.to_string()))
+                .cloned()
+        }
+
+        fn exists(&self, path: &Path) -> bool {
+            self.existing.contains(path)
+        }
+
+        fn read_to_string(&self, path: &Path) -> Option<String> {
+            self.files.get(path).cloned()
+        }
+
+        fn env_var(&self, name: &str) -> Option<String> {
+            self.env.get(name).cloned()
+        }
+
+        fn known_folder(&self, folder: KnownFolder) -> Option<PathBuf> {
+            self.known_folders.get(&folder).cloned()
+        }
+    }
+
+    fn steam_logs(library: &str) -> String {
+        steam_library_logs(Path::new(library)).to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn registry_is_preferred_over_steam() {
+        let resolver = MockPathResolver::default()
+            .with_launcher_install("C:\\Battlestate Games\\EFT")
+            .with_steam("C:\\Steam")
+            .with_dir(steam_logs("C:\\Steam"));
+
+        let info = probe_tarkov_installation(&resolver).unwrap();
+        assert_eq!(info.detected_via, DetectionSource::Registry);
+        assert_eq!(
+            info.log_directory,
+            PathBuf::from("C:\\Battlestate Games\\EFT").join("Logs").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn registry_entry_without_logs_falls_through_to_steam() {
+        let resolver = MockPathResolver::default()
+            .with_registry(RegistryHive::LocalMachine, LAUNCHER_UNINSTALL_KEY, "InstallLocation", "C:\\Gone")
+            .with_steam("C:\\Steam")
+            .with_dir(steam_logs("C:\\Steam"));
+
+        let info = probe_tarkov_installation(&resolver).unwrap();
+        assert_eq!(info.detected_via, DetectionSource::Steam);
+        assert_eq!(info.log_directory, steam_logs("C:\\Steam"));
+    }
+
+    #[test]
+    fn library_folders_vdf_is_parsed() {
+        let vdf = r#"
+"libraryfolders"
+{
+	"0"
+	{
+		"path"		"C:\\Program Files (x86)\\Steam"
+		"label"		""
+		"apps"
+		{
+			"228980"		"448965339"
+		}
+	}
+	"1"
+	{
+		"path"		"E:\\SteamLibrary"
+	}
+}
+"#;
+        assert_eq!(
+            parse_library_folders(vdf),
+            vec![
+                PathBuf::from("C:\\Program Files (x86)\\Steam"),
+                PathBuf::from("E:\\SteamLibrary"),
+            ]
+        );
+    }
+
+    #[test]
+    fn steam_libraries_from_vdf_are_searched() {
+        let steam_path = "C:\\Steam";
+        let vdf_path = PathBuf::from(steam_path).join("steamapps\\libraryfolders.vdf");
+        let resolver = MockPathResolver::default()
+            .with_steam(steam_path)
+            .with_file(vdf_path, "\"libraryfolders\"\n{\n\t\"1\"\n\t{\n\t\t\"path\"\t\t\"E:\\\\SteamLibrary\"\n\t}\n}\n")
+            .with_dir(steam_logs("E:\\SteamLibrary"));
+
+        assert_eq!(steam_candidates(&resolver), vec![steam_logs("E:\\SteamLibrary")]);
+    }
+
+    #[test]
+    fn steam_requires_registry_entry() {
+        let resolver = MockPathResolver::default().with_dir(steam_logs(FALLBACK_STEAM_LIBRARIES[0]));
+        assert!(steam_candidates(&resolver).is_empty());
+    }
+
+    #[test]
+    fn common_paths_are_tried_in_order() {
+        let resolver = MockPathResolver::default()
+            .with_dir(COMMON_PATHS[3])
+            .with_dir(COMMON_PATHS[1]);
+
+        assert_eq!(
+            common_path_candidates(&resolver),
+            vec![COMMON_PATHS[1].to_string(), COMMON_PATHS[3].to_string()]
+        );
+        let info = probe_tarkov_installation(&resolver).unwrap();
+        assert_eq!(info.detected_via, DetectionSource::CommonPath);
+        assert_eq!(info.log_directory, COMMON_PATHS[1]);
+    }
+
+    #[test]
+    fn steam_is_preferred_over_common_paths() {
+        let resolver = MockPathResolver::default()
+            .with_steam("C:\\Steam")
+            .with_dir(steam_logs("C:\\Steam"))
+            .with_dir(COMMON_PATHS[0]);
+
+        assert_eq!(probe_tarkov_installation(&resolver).unwrap().detected_via, DetectionSource::Steam);
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn nothing_found_is_an_error() {
+        assert!(probe_tarkov_installation(&MockPathResolver::default()).is_err());
+    }
+
+    #[test]
+    fn empty_directory_is_not_a_log_directory() {
+        let dir = TempDir::new().unwrap();
+        let validation = validate_log_directory(dir.path().to_str().unwrap(), false).unwrap();
+        assert!(!validation.valid);
+    }
+
+    #[test]
+    fn directory_with_log_file_is_a_log_directory() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("log_notifications.log"), "").unwrap();
+
+        let validation = validate_log_directory(dir.path().to_str().unwrap(), false).unwrap();
+        assert!(validation.valid);
+        assert!(validation.warnings.is_empty());
+    }
+
+    #[test]
+    fn missing_directory_is_not_a_log_directory() {
+        let dir = TempDir::new().unwrap();
+        let missing = dir.path().join("missing");
+        assert!(!validate_log_directory(missing.to_str().unwrap(), false).unwrap().valid);
+    }
+
+    #[test]
+    fn recursive_watch_warns_about_many_subdirectories() {
+        let dir = TempDir::new().unwrap();
+        for i in 0..=RECURSIVE_SUBDIRECTORY_WARNING {
+            std::fs::create_dir(dir.path().join(format!("log_{}", i))).unwrap();
+        }
+
+        let path = dir.path().to_str().unwrap();
+        assert!(validate_log_directory(path, false).unwrap().warnings.is_empty());
+        let validation = validate_log_directory(path, true).unwrap();
+        assert!(validation.valid);
+        assert_eq!(validation.warnings.len(), 1);
+    }
+
+    #[test]
+    fn onedrive_redirected_documents_resolve_to_the_physical_folder() {
+        let profile = Path::new("Users").join("player");
+        let onedrive_documents = profile.join("OneDrive").join("Documents");
+        let resolver = MockPathResolver::default()
+            .with_env("USERPROFILE", profile.to_str().unwrap())
+            .with_known_folder(KnownFolder::Documents, &onedrive_documents);
+
+        let logs = profile.join("Documents").join("Escape from Tarkov").join("Logs");
+        assert_eq!(
+            resolve_physical_path_with(&resolver, &logs),
+            onedrive_documents.join("Escape from Tarkov").join("Logs")
+        );
+    }
+
+    #[test]
+    fn paths_outside_known_folders_are_unchanged() {
+        let profile = Path::new("Users").join("player");
+        let resolver = MockPathResolver::default()
+            .with_env("USERPROFILE", profile.to_str().unwrap())
+            .with_known_folder(KnownFolder::Documents, profile.join("OneDrive").join("Documents"));
+
+        let logs = Path::new("Games").join("EFT").join("Logs");
+        assert_eq!(resolve_physical_path_with(&resolver, &logs), logs);
+    }
 }
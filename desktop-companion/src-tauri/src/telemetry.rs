@@ -0,0 +1,105 @@
+use crate::app_state::{AppState, CONFIG_STORE_KEY};
+use crate::error::AppResult;
+use crate::store;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Store key for the random per-install identity behind `install_hash`
+const IDENTITY_KEY: &str = "telemetry_identity";
+
+/// Usage events reported when the user has opted in
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TelemetryEvent {
+    AppStarted,
+    WatcherStarted,
+    WatcherStopped,
+    /// Sent without the quest's name or ID
+    QuestCompleted,
+    SyncCompleted,
+}
+
+/// Random install ID and the salt it is hashed with; neither ever leaves the machine
+#[derive(Serialize, Deserialize)]
+struct TelemetryIdentity {
+    install_id: String,
+    salt: String,
+}
+
+#[derive(Serialize)]
+struct TelemetryPayload {
+    event: TelemetryEvent,
+    install_hash: String,
+    app_version: &'static str,
+    /// Day only, so events can't be correlated with log timestamps
+    date: String,
+}
+
+/// Payload of the `telemetry-consent-changed` event
+#[derive(Clone, Serialize)]
+pub struct TelemetryConsentPayload {
+    pub enabled: bool,
+}
+
+/// Hex SHA-256 of `salt` followed by `value`
+fn salted_hash(salt: &str, value: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(value.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Load the install identity, creating and persisting one on first use
+fn identity(app: &AppHandle) -> TelemetryIdentity {
+    if let Some(identity) = store::load(app, IDENTITY_KEY) {
+        return identity;
+    }
+
+    let identity = TelemetryIdentity {
+        install_id: uuid::Uuid::new_v4().to_string(),
+        salt: uuid::Uuid::new_v4().simple().to_string(),
+    };
+    if let Err(e) = store::save(app, IDENTITY_KEY, &identity) {
+        eprintln!("Failed to persist telemetry identity: {}", e);
+    }
+    identity
+}
+
+/// Report `event` to the configured endpoint if telemetry is enabled
+pub fn track(app: &AppHandle, event: TelemetryEvent) {
+    let config = app.state::<AppState>().get_config();
+    let Some(endpoint) = config
+        .telemetry_endpoint
+        .filter(|url| config.telemetry_enabled && !url.is_empty())
+    else {
+        return;
+    };
+
+    let identity = identity(app);
+    let payload = TelemetryPayload {
+        event,
+        install_hash: salted_hash(&identity.salt, &identity.install_id),
+        app_version: env!("CARGO_PKG_VERSION"),
+        date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+    };
+
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = reqwest::Client::new().post(&endpoint).json(&payload).send().await {
+            eprintln!("Telemetry delivery failed: {}", e);
+        }
+    });
+}
+
+/// Record the user's answer to the consent prompt and emit `telemetry-consent-changed`
+pub fn set_consent(app: &AppHandle, enabled: bool) -> AppResult<()> {
+    let state = app.state::<AppState>();
+    let mut config = state.get_config();
+    config.telemetry_enabled = enabled;
+    config.telemetry_consent_asked = true;
+    store::save(app, CONFIG_STORE_KEY, &config)?;
+    state.set_config(config);
+
+    let _ = app.emit("telemetry-consent-changed", TelemetryConsentPayload { enabled });
+    Ok(())
+}
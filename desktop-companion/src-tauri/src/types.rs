@@ -0,0 +1,4 @@
+use std::collections::HashMap;
+
+/// Action ID -> accelerator string (e.g. `"show_window" -> "CmdOrCtrl+Shift+T"`)
+pub type HotkeyBindings = HashMap<String, String>;
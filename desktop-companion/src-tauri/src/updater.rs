@@ -0,0 +1,97 @@
+use crate::error::{AppError, AppResult};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+use tokio::time::Duration;
+
+/// Latest published release of this repository
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/DylanKnevitt/TarkovQuestTracker/releases/latest";
+
+/// Wait before the first check so startup isn't slowed down
+const STARTUP_DELAY: Duration = Duration::from_secs(30);
+
+/// Time between subsequent checks
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Result of comparing the running version against the latest release
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub current_version: String,
+    pub latest_version: String,
+    pub is_update_available: bool,
+    pub release_url: String,
+    pub release_notes: String,
+}
+
+/// Numeric components of a version tag (`v1.2.3-beta` -> `[1, 2, 3]`)
+fn version_parts(version: &str) -> Vec<u64> {
+    version
+        .trim_start_matches(['v', 'V'])
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// Whether `latest` is a strictly newer version than `current`
+fn is_newer(latest: &str, current: &str) -> bool {
+    let (mut latest, mut current) = (version_parts(latest), version_parts(current));
+    let len = latest.len().max(current.len());
+    latest.resize(len, 0);
+    current.resize(len, 0);
+    latest > current
+}
+
+/// Look up the latest GitHub release and compare it with this build
+pub async fn check_for_updates() -> AppResult<UpdateInfo> {
+    let response = reqwest::Client::new()
+        .get(LATEST_RELEASE_URL)
+        .header("User-Agent", concat!("tarkov-quest-companion/", env!("CARGO_PKG_VERSION")))
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(AppError::network_error(format!(
+            "GitHub responded with HTTP {}",
+            response.status().as_u16()
+        )));
+    }
+
+    let release: Value = response.json().await?;
+    let latest_version = release["tag_name"]
+        .as_str()
+        .ok_or_else(|| AppError::parse_error("release has no tag_name"))?
+        .to_string();
+    let current_version = env!("CARGO_PKG_VERSION").to_string();
+
+    Ok(UpdateInfo {
+        is_update_available: is_newer(&latest_version, &current_version),
+        current_version,
+        latest_version,
+        release_url: release["html_url"].as_str().unwrap_or_default().to_string(),
+        release_notes: release["body"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+/// Check for updates shortly after startup and then daily, emitting `update-available`
+pub fn spawn_update_checker(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(STARTUP_DELAY).await;
+
+        loop {
+            match check_for_updates().await {
+                Ok(info) if info.is_update_available => {
+                    let _ = app.emit("update-available", info);
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Update check failed: {}", e),
+            }
+
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
@@ -0,0 +1,108 @@
+use crate::app_state::AppState;
+use crate::error::{AppError, AppResult};
+use crate::log_parser::LogLine;
+use serde_json::{json, Map, Value};
+use tauri::{AppHandle, Manager};
+
+/// Embed colour for Discord messages (Tarkov tan)
+const DISCORD_EMBED_COLOR: u32 = 0x9A8866;
+
+/// Whether the URL is a Discord webhook, which needs an embed payload
+fn is_discord_webhook(url: &str) -> bool {
+    url.contains("discord.com/api/webhooks/") || url.contains("discordapp.com/api/webhooks/")
+}
+
+/// Generic JSON payload: `event_type`, `player_name`, `timestamp` plus event fields
+fn build_payload(event_type: &str, player_name: &str, fields: Map<String, Value>) -> Value {
+    let mut payload = Map::new();
+    payload.insert("event_type".to_string(), json!(event_type));
+    payload.insert("player_name".to_string(), json!(player_name));
+    payload.insert("timestamp".to_string(), json!(chrono::Utc::now().to_rfc3339()));
+    payload.extend(fields);
+    Value::Object(payload)
+}
+
+/// Discord embed built from a generic payload
+fn to_discord_embed(title: &str, payload: &Value) -> Value {
+    let fields: Vec<Value> = payload
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| !matches!(key.as_str(), "event_type" | "timestamp"))
+        .map(|(key, value)| {
+            let value = value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string());
+            json!({ "name": key, "value": value, "inline": true })
+        })
+        .collect();
+
+    json!({
+        "embeds": [{
+            "title": title,
+            "color": DISCORD_EMBED_COLOR,
+            "timestamp": payload["timestamp"],
+            "fields": fields,
+        }]
+    })
+}
+
+/// Event type, embed title and fields for lines that are posted
+fn describe(line: &LogLine) -> Option<(&'static str, String, Map<String, Value>)> {
+    let mut fields = Map::new();
+    match line {
+        LogLine::QuestCompleted { quest_id } => {
+            fields.insert("quest_id".to_string(), json!(quest_id));
+            Some(("QuestCompleted", "Quest completed".to_string(), fields))
+        }
+        LogLine::TraderLevelUp { trader, level } => {
+            fields.insert("trader".to_string(), json!(trader));
+            fields.insert("level".to_string(), json!(level));
+            Some(("TraderLevelUp", format!("{} loyalty level {}", trader, level), fields))
+        }
+        _ => None,
+    }
+}
+
+/// POST a payload to `url`, formatting it as an embed for Discord. Returns the HTTP status.
+async fn post(url: &str, title: &str, payload: Value) -> AppResult<u16> {
+    let body = if is_discord_webhook(url) {
+        to_discord_embed(title, &payload)
+    } else {
+        payload
+    };
+
+    let response = reqwest::Client::new().post(url).json(&body).send().await?;
+    Ok(response.status().as_u16())
+}
+
+/// Post `QuestCompleted` and `TraderLevelUp` lines to the configured webhook
+pub fn on_log_line(app: &AppHandle, line: &LogLine) {
+    let config = app.state::<AppState>().get_config();
+    let Some(url) = config.webhook_url.filter(|url| config.webhook_enabled && !url.is_empty()) else {
+        return;
+    };
+    let Some((event_type, title, fields)) = describe(line) else {
+        return;
+    };
+
+    let payload = build_payload(event_type, config.player_name.as_deref().unwrap_or_default(), fields);
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = post(&url, &title, payload).await {
+            eprintln!("Webhook delivery failed: {}", e);
+        }
+    });
+}
+
+/// Send a test message to the configured webhook
+pub async fn test_webhook(app: &AppHandle) -> AppResult<u16> {
+    let config = app.state::<AppState>().get_config();
+    let url = config
+        .webhook_url
+        .filter(|url| !url.is_empty())
+        .ok_or_else(|| AppError::invalid_config("webhook_url is not set"))?;
+
+    let mut fields = Map::new();
+    fields.insert("message".to_string(), json!("Webhook is configured correctly"));
+    let payload = build_payload("Test", config.player_name.as_deref().unwrap_or_default(), fields);
+
+    post(&url, "Tarkov Quest Companion test", payload).await
+}
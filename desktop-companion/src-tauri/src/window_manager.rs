@@ -0,0 +1,157 @@
+use crate::app_state::{AppState, WindowBounds, CONFIG_STORE_KEY};
+use crate::error::{AppError, AppResult};
+use crate::store;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, LogicalSize, Manager, PhysicalPosition, PhysicalSize, WebviewWindow};
+use windows::Win32::Foundation::{COLORREF, HWND};
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetWindowLongPtrW, SetLayeredWindowAttributes, SetWindowLongPtrW, GWL_EXSTYLE, LWA_ALPHA,
+    WS_EX_LAYERED,
+};
+
+/// Minimum opacity so the window never becomes invisible
+const MIN_OPACITY: f64 = 0.2;
+
+/// Compact HUD size (logical pixels)
+const COMPACT_SIZE: LogicalSize<f64> = LogicalSize::new(300.0, 100.0);
+
+/// Screen corner to snap the window to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum WindowCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+fn window_error(error: impl std::fmt::Display) -> AppError {
+    AppError::new("WINDOW_ERROR", "Window operation failed").with_details(error.to_string())
+}
+
+fn main_window(app: &AppHandle) -> AppResult<WebviewWindow> {
+    app.get_webview_window("main")
+        .ok_or_else(|| AppError::new("WINDOW_ERROR", "Main window is not available"))
+}
+
+/// Keep the window above other windows
+pub fn set_always_on_top(app: &AppHandle, enabled: bool) -> AppResult<()> {
+    main_window(app)?.set_always_on_top(enabled).map_err(window_error)
+}
+
+/// Set window opacity, clamped to 0.2–1.0
+pub fn set_window_opacity(app: &AppHandle, opacity: f64) -> AppResult<()> {
+    let window = main_window(app)?;
+    let alpha = (opacity.clamp(MIN_OPACITY, 1.0) * 255.0).round() as u8;
+    let hwnd = HWND(window.hwnd().map_err(window_error)?.0 as isize);
+
+    // SAFETY: hwnd belongs to our live main window
+    unsafe {
+        let style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, style | WS_EX_LAYERED.0 as isize);
+        SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA).map_err(window_error)
+    }
+}
+
+/// Snap the window to a corner of the monitor it is currently on
+pub fn move_window_to_corner(app: &AppHandle, corner: WindowCorner) -> AppResult<()> {
+    let window = main_window(app)?;
+    let monitor = window
+        .current_monitor()
+        .map_err(window_error)?
+        .ok_or_else(|| AppError::new("WINDOW_ERROR", "Window is not on any monitor"))?;
+
+    // Work area excludes the taskbar; margin scales with the monitor's DPI
+    let area = monitor.work_area();
+    let margin = (app.state::<AppState>().get_config().window_corner_margin as f64
+        * monitor.scale_factor())
+    .round() as i32;
+    let size = window.outer_size().map_err(window_error)?;
+
+    let left = area.position.x + margin;
+    let top = area.position.y + margin;
+    let right = area.position.x + area.size.width as i32 - size.width as i32 - margin;
+    let bottom = area.position.y + area.size.height as i32 - size.height as i32 - margin;
+
+    let (x, y) = match corner {
+        WindowCorner::TopLeft => (left, top),
+        WindowCorner::TopRight => (right, top),
+        WindowCorner::BottomLeft => (left, bottom),
+        WindowCorner::BottomRight => (right, bottom),
+    };
+
+    window
+        .set_position(PhysicalPosition::new(x, y))
+        .map_err(window_error)
+}
+
+/// Switch between the compact 300×100 HUD and the previous size. Returns `true` when compact.
+pub fn toggle_compact_mode(app: &AppHandle) -> AppResult<bool> {
+    let window = main_window(app)?;
+    let state = app.state::<AppState>();
+    let mut restore_size = state.compact_restore_size.lock()?;
+
+    match restore_size.take() {
+        Some(size) => {
+            window.set_size(size).map_err(window_error)?;
+            Ok(false)
+        }
+        None => {
+            *restore_size = Some(window.inner_size().map_err(window_error)?);
+            window.set_size(COMPACT_SIZE).map_err(window_error)?;
+            Ok(true)
+        }
+    }
+}
+
+/// Persist the window's current bounds to `AppConfig`
+pub fn save_window_position(app: &AppHandle) -> AppResult<WindowBounds> {
+    let window = main_window(app)?;
+    let position = window.outer_position().map_err(window_error)?;
+    let size = window.inner_size().map_err(window_error)?;
+    let bounds = WindowBounds {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    };
+
+    let state = app.state::<AppState>();
+    let mut config = state.get_config();
+    config.window_bounds = Some(bounds);
+    state.set_config(config.clone());
+    store::save(app, CONFIG_STORE_KEY, &config)?;
+
+    Ok(bounds)
+}
+
+/// Apply bounds saved by `save_window_position`, if any
+pub fn restore_window_position(app: &AppHandle) -> AppResult<()> {
+    let Some(bounds) = app.state::<AppState>().get_config().window_bounds else {
+        return Ok(());
+    };
+    let window = main_window(app)?;
+
+    // Skip positions left over from a monitor that has since been unplugged
+    let on_screen = window
+        .available_monitors()
+        .map_err(window_error)?
+        .iter()
+        .any(|monitor| {
+            let area = monitor.work_area();
+            bounds.x >= area.position.x
+                && bounds.y >= area.position.y
+                && bounds.x < area.position.x + area.size.width as i32
+                && bounds.y < area.position.y + area.size.height as i32
+        });
+
+    window
+        .set_size(PhysicalSize::new(bounds.width, bounds.height))
+        .map_err(window_error)?;
+    if on_screen {
+        window
+            .set_position(PhysicalPosition::new(bounds.x, bounds.y))
+            .map_err(window_error)?;
+    }
+
+    Ok(())
+}
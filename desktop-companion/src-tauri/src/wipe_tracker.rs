@@ -0,0 +1,59 @@
+use crate::log_parser::LogLine;
+use crate::store;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use tauri::{AppHandle, Emitter};
+
+/// Store key for the last character level seen in the logs
+const LAST_LEVEL_KEY: &str = "last_character_level";
+
+/// Historical wipes, maintained by hand each patch
+static WIPES_JSON: &str = include_str!("../data/wipes.json");
+
+/// A wipe and the patch that introduced it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WipeInfo {
+    pub patch: String,
+    pub date: NaiveDate,
+}
+
+fn wipes() -> &'static [WipeInfo] {
+    static WIPES: OnceLock<Vec<WipeInfo>> = OnceLock::new();
+    WIPES.get_or_init(|| serde_json::from_str(WIPES_JSON).expect("data/wipes.json is malformed"))
+}
+
+/// Most recent wipe in the embedded list
+pub fn get_latest_wipe() -> WipeInfo {
+    wipes()
+        .iter()
+        .max_by_key(|wipe| wipe.date)
+        .cloned()
+        .expect("data/wipes.json must list at least one wipe")
+}
+
+/// Whole days elapsed since the latest wipe
+pub fn days_since_wipe() -> u32 {
+    let elapsed = Utc::now().date_naive() - get_latest_wipe().date;
+    elapsed.num_days().max(0) as u32
+}
+
+/// Emit `wipe-detected` when the character level drops back to 1.
+///
+/// Returns `true` when a wipe was detected.
+pub fn on_log_line(app: &AppHandle, line: &LogLine) -> bool {
+    let LogLine::ProfileLevel { level } = line else {
+        return false;
+    };
+
+    let previous: Option<u32> = store::load(app, LAST_LEVEL_KEY);
+    if let Err(e) = store::save(app, LAST_LEVEL_KEY, level) {
+        eprintln!("Failed to persist character level: {}", e);
+    }
+
+    let wiped = *level == 1 && previous.is_some_and(|previous| previous > 1);
+    if wiped {
+        let _ = app.emit("wipe-detected", get_latest_wipe());
+    }
+    wiped
+}
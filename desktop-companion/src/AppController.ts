@@ -1,8 +1,11 @@
 import { listen } from '@tauri-apps/api/event';
 import {
     getAppConfig,
+    getSupabaseKey,
+    setTelemetryConsent,
     startLogWatcher,
     updateTrayIcon,
+    type AppError,
     type LogEvent,
 } from './services/tauri-commands';
 import { supabaseService } from './services/SupabaseService';
@@ -29,8 +32,8 @@ export class AppController {
         }
 
         // Initialize Supabase client
-        if (config.supabase_url && config.supabase_key) {
-            const initialized = supabaseService.initialize(config.supabase_url, config.supabase_key);
+        if (config.supabase_url && config.supabase_key_set) {
+            const initialized = supabaseService.initialize(config.supabase_url, await getSupabaseKey());
             if (initialized) {
                 console.log('Supabase client initialized');
                 this.setConnectionStatus('Connected');
@@ -46,8 +49,13 @@ export class AppController {
         });
 
         // Listen for log errors
-        await listen<string>('log-error', (event) => {
-            console.error('Log watcher error:', event.payload);
+        await listen<AppError>('log-error', (event) => {
+            const error = event.payload;
+            if (error.severity === 'Info' || error.severity === 'Warning') {
+                console.warn('Log watcher warning:', error.message, error.details);
+                return;
+            }
+            console.error('Log watcher error:', error.message, error.details);
             this.setConnectionStatus('Disconnected');
         });
 
@@ -61,6 +69,15 @@ export class AppController {
             await Notification.requestPermission();
         }
 
+        // Ask once for opt-in usage statistics
+        if (!config.telemetry_consent_asked) {
+            const enabled = window.confirm(
+                'Help improve Tarkov Quest Companion by sending anonymous usage statistics? ' +
+                'No quest, account or player details are included.'
+            );
+            await setTelemetryConsent(enabled);
+        }
+
         // Auto-start log watching if configured
         if (config.log_directory) {
             await this.startWatching(config.log_directory);
@@ -424,9 +424,9 @@ export class FirstRunWizard {
       status.textContent = 'Validating...';
       status.className = 'status-message info';
 
-      const isValid = await validateLogDirectory(path);
+      const validation = await validateLogDirectory(path);
 
-      if (isValid) {
+      if (validation.valid) {
         this.config.log_directory = path;
         status.textContent = '✓ Valid Tarkov directory!';
         status.className = 'status-message success';
@@ -496,7 +496,7 @@ export class FirstRunWizard {
       const fullConfig: AppConfig = {
         log_directory: this.config.log_directory || '',
         supabase_url: this.config.supabase_url || '',
-        supabase_key: this.config.supabase_key || '',
+        supabase_key: this.config.supabase_key || null,
         auto_start: this.config.auto_start ?? true,
         notifications_enabled: this.config.notifications_enabled ?? true,
         sync_enabled: this.config.sync_enabled ?? true,
@@ -2,14 +2,16 @@
 import { open } from '@tauri-apps/plugin-dialog';
 import {
     getAppConfig,
+    getSupabaseKey,
     saveAppConfig,
     autoDetectLogDirectory,
     validateLogDirectory,
     type AppConfig,
+    type SafeAppConfig,
 } from '../services/tauri-commands';
 
 export class SettingsComponent {
-    private config: AppConfig | null = null;
+    private config: SafeAppConfig | null = null;
     private isValidated = false;
 
     private elements = {
@@ -21,6 +23,7 @@ export class SettingsComponent {
 
         supabaseUrlInput: document.getElementById('supabase-url') as HTMLInputElement,
         supabaseKeyInput: document.getElementById('supabase-key') as HTMLInputElement,
+        clearSupabaseKeyCheckbox: document.getElementById('clear-supabase-key') as HTMLInputElement,
         testConnectionBtn: document.getElementById('test-connection-btn') as HTMLButtonElement,
         connectionStatus: document.getElementById('connection-status') as HTMLDivElement,
 
@@ -29,6 +32,7 @@ export class SettingsComponent {
         signoutStatus: document.getElementById('signout-status') as HTMLDivElement,
 
         autoStartCheckbox: document.getElementById('auto-start') as HTMLInputElement,
+        startMinimizedCheckbox: document.getElementById('start-minimized') as HTMLInputElement,
         notificationsCheckbox: document.getElementById('notifications') as HTMLInputElement,
         syncEnabledCheckbox: document.getElementById('sync-enabled') as HTMLInputElement,
 
@@ -61,8 +65,12 @@ export class SettingsComponent {
 
         this.elements.logDirectoryInput.value = this.config.log_directory || '';
         this.elements.supabaseUrlInput.value = this.config.supabase_url || '';
-        this.elements.supabaseKeyInput.value = this.config.supabase_key || '';
+        // Leave the stored key in the backend; a blank field keeps it
+        this.elements.supabaseKeyInput.value = '';
+        this.elements.supabaseKeyInput.placeholder = this.config.supabase_key_set ? 'Key saved - leave blank to keep' : '';
+        this.elements.clearSupabaseKeyCheckbox.checked = false;
         this.elements.autoStartCheckbox.checked = this.config.auto_start;
+        this.elements.startMinimizedCheckbox.checked = this.config.start_minimized ?? false;
         this.elements.notificationsCheckbox.checked = this.config.notifications_enabled;
         this.elements.syncEnabledCheckbox.checked = this.config.sync_enabled;
     }
@@ -70,9 +78,9 @@ export class SettingsComponent {
     private async loadUserInfo() {
         try {
             // Initialize Supabase client first
-            if (this.config?.supabase_url && this.config?.supabase_key) {
+            if (this.config?.supabase_url && this.config?.supabase_key_set) {
                 const { supabaseService } = await import('../services/SupabaseService');
-                supabaseService.initialize(this.config.supabase_url, this.config.supabase_key);
+                supabaseService.initialize(this.config.supabase_url, await getSupabaseKey());
 
                 const user = await supabaseService.getCurrentUser();
 
@@ -158,10 +166,11 @@ export class SettingsComponent {
             this.elements.validateBtn.disabled = true;
             this.elements.validateBtn.textContent = 'Validating...';
 
-            const isValid = await validateLogDirectory(path);
+            const validation = await validateLogDirectory(path);
 
-            if (isValid) {
-                this.showValidationSuccess('✓ Valid Tarkov log directory');
+            if (validation.valid) {
+                const warnings = validation.warnings.length > 0 ? ` (${validation.warnings.join('; ')})` : '';
+                this.showValidationSuccess(`✓ Valid Tarkov log directory${warnings}`);
                 this.isValidated = true;
             } else {
                 this.showValidationError('✗ Invalid directory. Make sure it contains Tarkov log files.');
@@ -179,7 +188,8 @@ export class SettingsComponent {
 
     private async handleTestConnection() {
         const url = this.elements.supabaseUrlInput.value.trim();
-        const key = this.elements.supabaseKeyInput.value.trim();
+        const key = this.elements.supabaseKeyInput.value.trim()
+            || (this.config?.supabase_key_set ? await getSupabaseKey() : '');
 
         if (!url || !key) {
             this.showConnectionError('Please enter both Supabase URL and API key');
@@ -224,8 +234,12 @@ export class SettingsComponent {
         const config: AppConfig = {
             log_directory: this.elements.logDirectoryInput.value.trim(),
             supabase_url: this.elements.supabaseUrlInput.value.trim(),
-            supabase_key: this.elements.supabaseKeyInput.value.trim(),
+            // null leaves the saved key untouched, an empty string clears it
+            supabase_key: this.elements.clearSupabaseKeyCheckbox.checked
+                ? ''
+                : this.elements.supabaseKeyInput.value.trim() || null,
             auto_start: this.elements.autoStartCheckbox.checked,
+            start_minimized: this.elements.startMinimizedCheckbox.checked,
             notifications_enabled: this.elements.notificationsCheckbox.checked,
             sync_enabled: this.elements.syncEnabledCheckbox.checked,
         };
@@ -237,7 +251,7 @@ export class SettingsComponent {
             const success = await saveAppConfig(config);
 
             if (success) {
-                this.config = config;
+                this.config = await getAppConfig();
                 this.showSuccess('Settings saved successfully!');
 
                 // Close settings window after a short delay
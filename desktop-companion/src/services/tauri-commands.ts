@@ -7,29 +7,117 @@ export interface AppConfig {
     auto_start: boolean;
     notifications_enabled: boolean;
     sync_enabled: boolean;
+    health_check_interval_secs?: number;
+    sync_rate_limit_per_second?: number;
+    hotkey_bindings?: Record<string, string>;
+    notification_volume?: number;
+    window_bounds?: WindowBounds | null;
+    tray_click_action?: TrayClickAction;
+    start_minimized?: boolean;
+    minimize_to_tray?: boolean;
+    window_corner_margin?: number;
+    watcher_batch_interval_ms?: number;
+    max_buffered_events?: number;
+    log_file_patterns?: string[];
+    recursive_log_watch?: boolean;
+    mmap_threshold_bytes?: number;
+    backup_directory?: string | null;
+    backup_interval_mins?: number;
+    webhook_url?: string | null;
+    webhook_enabled?: boolean;
+    player_name?: string | null;
+    telemetry_enabled?: boolean;
+    telemetry_endpoint?: string | null;
+    telemetry_consent_asked?: boolean;
 }
 
+/** `AppConfig` as returned by `get_app_config`; the key itself stays in the backend */
+export type SafeAppConfig = Omit<AppConfig, 'supabase_key'> & {
+    supabase_key_set: boolean;
+};
+
+export interface WindowBounds {
+    x: number;
+    y: number;
+    width: number;
+    height: number;
+}
+
+export type TrayClickAction = 'AlwaysShow' | 'Toggle' | 'ShowMenu';
+
 export type WatcherStatus =
     | { Stopped: null }
     | { Running: null }
+    | { Reconnecting: null }
     | { Error: string };
 
+export type LogType = 'Notifications' | 'Application' | 'Unknown';
+
 export interface LogEvent {
     file_path: string;
+    log_type: LogType;
     content: string;
     timestamp: string;
+    file_modified_at: string | null;
+}
+
+export type ConnectionStatus = 'Connected' | 'Disconnected' | 'Syncing' | 'Subscribed';
+
+export interface DeepLinkPayload {
+    scheme: string;
+    path: string;
+    query: Record<string, string>;
+}
+
+export interface ImportResult {
+    imported: number;
+    skipped: number;
+    errors: string[];
 }
 
-export type ConnectionStatus = 'Connected' | 'Disconnected' | 'Syncing';
+export type SyncOperation = 'Upsert' | 'Delete';
+
+export interface SyncItem {
+    id: string;
+    operation: SyncOperation;
+    payload: unknown;
+    created_at: string;
+    attempts: number;
+}
+
+export interface SyncConflict {
+    local: SyncItem;
+    server: unknown;
+}
+
+export type ConflictResolution = 'TakeLocal' | 'TakeServer' | { Merge: unknown };
+
+export type ErrorSeverity = 'Info' | 'Warning' | 'Error' | 'Fatal';
+
+export interface AppError {
+    code: string;
+    message: string;
+    details: string | null;
+    severity: ErrorSeverity;
+    recoverable: boolean;
+    retry_count: number;
+    max_retries: number;
+    retry_after_secs: number | null;
+    correlation_id: string | null;
+}
 
 // ============================================================================
 // IPC Command Wrappers
 // ============================================================================
 
-export async function getAppConfig(): Promise<AppConfig> {
+export async function getAppConfig(): Promise<SafeAppConfig> {
     return await invoke('get_app_config');
 }
 
+export async function getSupabaseKey(): Promise<string> {
+    return await invoke('get_supabase_key');
+}
+
 export async function saveAppConfig(config: AppConfig): Promise<boolean> {
     return await invoke('save_app_config', { config });
 }
@@ -38,18 +126,63 @@ export async function autoDetectLogDirectory(): Promise<string> {
     return await invoke('auto_detect_log_directory');
 }
 
-export async function validateLogDirectory(path: string): Promise<boolean> {
+export interface LogDirectoryValidation {
+    valid: boolean;
+    warnings: string[];
+}
+
+export async function validateLogDirectory(path: string): Promise<LogDirectoryValidation> {
     return await invoke('validate_log_directory', { path });
 }
 
-export async function startLogWatcher(logDirectory: string): Promise<string> {
-    return await invoke('start_log_watcher', { logDirectory });
+export async function startLogWatcher(logPath?: string): Promise<string> {
+    return await invoke('start_log_watcher', { logPath });
 }
 
 export async function stopLogWatcher(): Promise<boolean> {
     return await invoke('stop_log_watcher');
 }
 
+export async function setWatcherBatchInterval(ms: number): Promise<void> {
+    return await invoke('set_watcher_batch_interval', { ms });
+}
+
+export interface LogFileInfo {
+    path: string;
+    name: string;
+    size_bytes: number;
+    modified_at: string;
+}
+
+export async function listLogFiles(directory: string, offset = 0, limit = 50): Promise<LogFileInfo[]> {
+    return await invoke('list_log_files', { directory, offset, limit });
+}
+
+export async function readLogTail(path: string, maxLines: number): Promise<string[]> {
+    return await invoke('read_log_tail', { path, maxLines });
+}
+
+export interface SearchResult {
+    file_path: string;
+    line_number: number;
+    line: string;
+    matched_range: [number, number];
+    context_before: string[];
+    context_after: string[];
+}
+
+export async function searchLogEvents(directory: string, pattern: string, maxResults = 100): Promise<SearchResult[]> {
+    return await invoke('search_log_events', { directory, pattern, maxResults });
+}
+
+export async function testLogFilePattern(fileName: string): Promise<boolean> {
+    return await invoke('test_log_file_pattern', { fileName });
+}
+
+export async function getWatcherBufferDepth(): Promise<number> {
+    return await invoke('get_watcher_buffer_depth');
+}
+
 export async function getWatcherStatus(): Promise<WatcherStatus> {
     return await invoke('get_watcher_status');
 }
@@ -57,3 +190,664 @@ export async function getWatcherStatus(): Promise<WatcherStatus> {
 export async function updateTrayIcon(status: ConnectionStatus): Promise<void> {
     return await invoke('update_tray_icon', { status });
 }
+
+export async function enqueueSyncItem(operation: SyncOperation, payload: unknown): Promise<string> {
+    return await invoke('enqueue_sync_item', { operation, payload });
+}
+
+export async function getSyncQueueDepth(): Promise<number> {
+    return await invoke('get_sync_queue_depth');
+}
+
+export async function getLastSyncedAt(): Promise<string | null> {
+    return await invoke('get_last_synced_at');
+}
+
+export async function resolveConflict(itemId: string, resolution: ConflictResolution): Promise<void> {
+    return await invoke('resolve_conflict', { itemId, resolution });
+}
+
+export async function startRealtimeSync(userId: string, accessToken?: string): Promise<boolean> {
+    return await invoke('start_realtime_sync', { userId, accessToken });
+}
+
+export async function stopRealtimeSync(): Promise<boolean> {
+    return await invoke('stop_realtime_sync');
+}
+
+export async function getConnectionLatencyMs(): Promise<number | null> {
+    return await invoke('get_connection_latency_ms');
+}
+
+export async function isGameRunning(): Promise<boolean> {
+    return await invoke('is_game_running');
+}
+
+export async function getGameProcessPid(): Promise<number | null> {
+    return await invoke('get_game_process_pid');
+}
+
+export async function enableAutoStart(minimizeOnStart: boolean): Promise<void> {
+    return await invoke('enable_auto_start', { minimizeOnStart });
+}
+
+export async function disableAutoStart(): Promise<void> {
+    return await invoke('disable_auto_start');
+}
+
+export async function isAutoStartEnabled(): Promise<boolean> {
+    return await invoke('is_auto_start_enabled');
+}
+
+export async function isPrimaryInstance(): Promise<boolean> {
+    return await invoke('is_primary_instance');
+}
+
+export async function takePendingDeepLink(): Promise<DeepLinkPayload | null> {
+    return await invoke('take_pending_deep_link');
+}
+
+export async function exportProgressJson(): Promise<string> {
+    return await invoke('export_progress_json');
+}
+
+export async function exportProgressCsv(): Promise<string> {
+    return await invoke('export_progress_csv');
+}
+
+export async function importProgressJson(json: string): Promise<ImportResult> {
+    return await invoke('import_progress_json', { json });
+}
+
+export async function copyQuestSummaryToClipboard(questIds: string[]): Promise<void> {
+    return await invoke('copy_quest_summary_to_clipboard', { questIds });
+}
+
+export async function copySessionStatsToClipboard(): Promise<void> {
+    return await invoke('copy_session_stats_to_clipboard');
+}
+
+export type HotkeyAction = 'show_window' | 'toggle_window' | 'copy_session_stats';
+
+export async function registerHotkey(id: HotkeyAction | string, shortcut: string): Promise<void> {
+    return await invoke('register_hotkey', { id, shortcut });
+}
+
+export async function unregisterHotkey(id: HotkeyAction | string): Promise<void> {
+    return await invoke('unregister_hotkey', { id });
+}
+
+export interface NotificationAction {
+    label: string;
+    action: string;
+}
+
+export interface NotificationPayload {
+    title: string;
+    body: string;
+    hero_image?: string | null;
+    actions: NotificationAction[];
+    tag: string;
+}
+
+export interface NotificationActionEvent {
+    tag: string;
+    action: string;
+}
+
+export async function sendNotification(payload: NotificationPayload): Promise<void> {
+    return await invoke('send_notification', { payload });
+}
+
+export type SoundEffect = 'QuestComplete' | 'LevelUp' | 'RaidDied' | 'SyncComplete' | 'Error';
+
+export async function playSound(effect: SoundEffect): Promise<void> {
+    return await invoke('play_sound', { effect });
+}
+
+export async function testNotificationSound(): Promise<void> {
+    return await invoke('test_notification_sound');
+}
+
+export type WindowCorner = 'TopLeft' | 'TopRight' | 'BottomLeft' | 'BottomRight';
+
+export async function setAlwaysOnTop(enabled: boolean): Promise<void> {
+    return await invoke('set_always_on_top', { enabled });
+}
+
+export async function setWindowOpacity(opacity: number): Promise<void> {
+    return await invoke('set_window_opacity', { opacity });
+}
+
+export async function moveWindowToCorner(corner: WindowCorner): Promise<void> {
+    return await invoke('move_window_to_corner', { corner });
+}
+
+export async function toggleCompactMode(): Promise<boolean> {
+    return await invoke('toggle_compact_mode');
+}
+
+export async function saveWindowPosition(): Promise<WindowBounds> {
+    return await invoke('save_window_position');
+}
+
+export interface QuestMeta {
+    id: string;
+    name: string;
+    trader: string;
+    objectives: string[];
+    map: string | null;
+    wiki_url: string;
+}
+
+export async function getQuestMeta(questId: string): Promise<QuestMeta> {
+    return await invoke('get_quest_meta', { questId });
+}
+
+export interface WipeInfo {
+    patch: string;
+    date: string;
+}
+
+export async function getWipeInfo(): Promise<WipeInfo> {
+    return await invoke('get_wipe_info');
+}
+
+export async function daysSinceWipe(): Promise<number> {
+    return await invoke('days_since_wipe');
+}
+
+export type AchievementCondition =
+    | { QuestsCompleted: number }
+    | { RaidsSurvived: number }
+    | { TraderLevelReached: { trader: string; level: number } }
+    | { SessionStreak: number };
+
+export interface Achievement {
+    id: string;
+    name: string;
+    description: string;
+    condition: AchievementCondition;
+}
+
+export interface AchievementStatus extends Achievement {
+    unlocked: boolean;
+    unlocked_at: string | null;
+}
+
+export async function getAchievements(): Promise<AchievementStatus[]> {
+    return await invoke('get_achievements');
+}
+
+export interface MapStats {
+    raids: number;
+    survived: number;
+    kills: number;
+}
+
+export interface AllTimeStats {
+    raids_started: number;
+    raids_survived: number;
+    raids_died: number;
+    kills: number;
+    quests_completed: number;
+    per_map: Record<string, MapStats>;
+}
+
+export interface SessionStats extends AllTimeStats {
+    flea_earnings_rub: number;
+    session_start: string;
+    all_time: AllTimeStats;
+}
+
+export async function getSessionStats(): Promise<SessionStats> {
+    return await invoke('get_session_stats');
+}
+
+export async function getSessionStatsByMap(): Promise<Record<string, MapStats>> {
+    return await invoke('get_session_stats_by_map');
+}
+
+export interface FleaMarketSalePayload {
+    item_name: string;
+    count: number;
+    price_rub: number;
+    fee: number;
+    net_rub: number;
+    session_earnings_rub: number;
+}
+
+export async function getFleaEarnings(): Promise<number> {
+    return await invoke('get_flea_earnings');
+}
+
+export async function resetSessionStats(): Promise<void> {
+    return await invoke('reset_session_stats');
+}
+
+export interface CharacterDetectedPayload {
+    character_name: string;
+    profile_id: string | null;
+    game_version: string;
+}
+
+export interface AccountSwitchedPayload {
+    previous: string;
+    current: string;
+}
+
+export interface RaidStartedPayload {
+    map: string;
+    mode: string;
+    timestamp: string;
+}
+
+export interface RaidEndedPayload {
+    survived: boolean;
+    duration_secs: number;
+    timestamp: string;
+}
+
+export interface QuestCompletedPayload {
+    quest_id: string;
+    quest_name: string;
+    trader: string;
+    timestamp: string;
+}
+
+export interface WatcherDirectoryPayload {
+    directory: string;
+    timestamp: string;
+}
+
+export interface WatcherStoppedPayload {
+    reason: string;
+    timestamp: string;
+}
+
+export interface BufferOverflowPayload {
+    dropped_file: string;
+    max_buffered_events: number;
+}
+
+export interface LogCorruptionPayload {
+    file_path: string;
+    timestamp: string;
+}
+
+export interface SkillLevelUpPayload {
+    skill: string;
+    new_level: number;
+}
+
+export async function getSkillLevels(): Promise<Record<string, number>> {
+    return await invoke('get_skill_levels');
+}
+
+export interface KarmaThresholdPayload {
+    threshold: number;
+    previous: number;
+    current: number;
+    reason: string;
+}
+
+export async function getFenceReputation(): Promise<number> {
+    return await invoke('get_fence_reputation');
+}
+
+export async function getTraderLevels(): Promise<Record<string, number>> {
+    return await invoke('get_trader_levels');
+}
+
+export type ItemUrgency = 'High' | 'Normal';
+
+export interface QuestItemMatch {
+    quest_id: string;
+    quest_name: string;
+    urgency: ItemUrgency;
+}
+
+export interface QuestItemFoundPayload {
+    item_id: string;
+    item_name: string;
+    count: number;
+    urgency: ItemUrgency;
+    quests: QuestItemMatch[];
+}
+
+export interface InsuranceReturnedPayload {
+    trader: string;
+    item_count: number;
+}
+
+export async function getFoundItems(): Promise<Record<string, number>> {
+    return await invoke('get_found_items');
+}
+
+export async function clearFoundItems(): Promise<void> {
+    return await invoke('clear_found_items');
+}
+
+export interface NotificationRule {
+    event_type: string;
+    enabled: boolean;
+    title_template: string;
+    body_template: string;
+    play_sound: boolean;
+    sound_effect: SoundEffect | null;
+}
+
+export async function getNotificationRules(): Promise<NotificationRule[]> {
+    return await invoke('get_notification_rules');
+}
+
+export async function saveNotificationRules(rules: NotificationRule[]): Promise<void> {
+    return await invoke('save_notification_rules', { rules });
+}
+
+export async function resetNotificationRulesToDefaults(): Promise<NotificationRule[]> {
+    return await invoke('reset_notification_rules_to_defaults');
+}
+
+export interface UpdateInfo {
+    current_version: string;
+    latest_version: string;
+    is_update_available: boolean;
+    release_url: string;
+    release_notes: string;
+}
+
+export async function checkForUpdates(): Promise<UpdateInfo> {
+    return await invoke('check_for_updates');
+}
+
+export interface BackupInfo {
+    path: string;
+    file_name: string;
+    size_bytes: number;
+    created_at: string;
+}
+
+export async function createBackup(): Promise<string> {
+    return await invoke('create_backup');
+}
+
+export async function listBackups(): Promise<BackupInfo[]> {
+    return await invoke('list_backups');
+}
+
+export async function restoreFromBackup(path: string): Promise<ImportResult> {
+    return await invoke('restore_from_backup', { path });
+}
+
+export async function testWebhook(): Promise<string> {
+    return await invoke('test_webhook');
+}
+
+export interface SystemInfo {
+    os_name: string;
+    os_version: string;
+    cpu_brand: string;
+    total_memory_mb: number;
+    free_memory_mb: number;
+    disk_free_bytes: Record<string, number>;
+    app_version: string;
+    tauri_version: string;
+}
+
+export interface DiagnosticsSnapshot {
+    captured_at: string;
+    system: SystemInfo;
+    watcher_status: WatcherStatus;
+    is_watching: boolean;
+    connected: boolean;
+    connection_latency_ms: number | null;
+    sync_queue_depth: number;
+    game_running: boolean;
+    items_found_in_raid: number;
+    corrupted_reads: number;
+}
+
+export async function getSystemInfo(): Promise<SystemInfo> {
+    return await invoke('get_system_info');
+}
+
+export async function getDiagnosticsSnapshot(): Promise<DiagnosticsSnapshot> {
+    return await invoke('get_diagnostics_snapshot');
+}
+
+export type HealthStatus = 'Healthy' | 'Degraded' | 'Unhealthy';
+
+export interface HealthReport {
+    status: HealthStatus;
+    watcher_running: boolean;
+    supabase_connected: boolean;
+    last_event_age_secs: number | null;
+    sync_queue_depth: number;
+    memory_rss_bytes: number;
+}
+
+export async function getHealthReport(): Promise<HealthReport> {
+    return await invoke('get_health_report');
+}
+
+export interface PingResult {
+    reachable: boolean;
+    latency_ms: number;
+    status_code: number | null;
+    error: string | null;
+}
+
+export async function pingSupabase(): Promise<PingResult> {
+    return await invoke('ping_supabase');
+}
+
+export interface SyncSummary {
+    pushed: number;
+    failed: number;
+    conflicts: number;
+    duration_ms: number;
+}
+
+export interface SyncProgress {
+    current: number;
+    total: number;
+}
+
+export async function forceSync(): Promise<SyncSummary> {
+    return await invoke('force_sync');
+}
+
+export async function openLogDirectory(): Promise<void> {
+    await invoke('open_log_directory');
+}
+
+export async function revealFileInDirectory(path: string): Promise<void> {
+    await invoke('reveal_file_in_directory', { path });
+}
+
+export interface DiskSpaceInfo {
+    drive: string;
+    free_bytes: number;
+    total_bytes: number;
+    log_dir_size_bytes: number;
+}
+
+export async function getDiskSpace(): Promise<DiskSpaceInfo> {
+    return await invoke('get_disk_space');
+}
+
+export interface BsgProfile {
+    username: string;
+    account_id: string;
+    profile_path: string;
+}
+
+export async function listBsgProfiles(): Promise<BsgProfile[]> {
+    return await invoke('list_bsg_profiles');
+}
+
+export type DetectionSource = 'registry' | 'steam' | 'common_path' | 'running_process';
+
+export interface TarkovInstallInfo {
+    log_directory: string;
+    detected_via: DetectionSource;
+    detected_via_label: string;
+}
+
+export async function detectTarkovInstallation(): Promise<TarkovInstallInfo> {
+    return await invoke('detect_tarkov_installation');
+}
+
+export interface RankedInstallInfo extends TarkovInstallInfo {
+    confidence_score: number;
+}
+
+export async function listTarkovInstallations(): Promise<RankedInstallInfo[]> {
+    return await invoke('list_tarkov_installations');
+}
+
+export async function bustDetectionCache(): Promise<void> {
+    return await invoke('bust_detection_cache');
+}
+
+export interface QuestCompletion {
+    quest_id: string;
+    quest_name: string;
+    trader: string;
+    completed_at: string;
+}
+
+export async function getRecentCompletions(limit = 10): Promise<QuestCompletion[]> {
+    return await invoke('get_recent_completions', { limit });
+}
+
+export async function restartLogWatcher(newDirectory?: string): Promise<string> {
+    return await invoke('restart_log_watcher', { newDirectory });
+}
+
+export async function getAppVersion(): Promise<string> {
+    return await invoke('get_app_version');
+}
+
+export interface BuildInfo {
+    version: string;
+    git_hash?: string;
+    build_date: string;
+    target_triple: string;
+}
+
+export async function getBuildInfo(): Promise<BuildInfo> {
+    return await invoke('get_build_info');
+}
+
+export interface WatcherDetails {
+    status: WatcherStatus;
+    directory?: string;
+    started_at?: string;
+    events_processed: number;
+    last_event_at?: string;
+    buffer_depth: number;
+    pipeline_depth: number;
+}
+
+export async function getWatcherDetails(): Promise<WatcherDetails> {
+    return await invoke('get_watcher_details');
+}
+
+export async function requestResetToken(): Promise<string> {
+    return await invoke('request_reset_token');
+}
+
+export async function resetAppToDefaults(confirmationToken: string, deleteBackups = false): Promise<void> {
+    return await invoke('reset_app_to_defaults', { confirmationToken, deleteBackups });
+}
+
+export interface WatcherLogStats {
+    events_per_minute: number;
+    bytes_per_minute: number;
+    total_events: number;
+    total_bytes: number;
+    error_count: number;
+    dropped_events: number;
+    last_file_path?: string;
+}
+
+export async function getWatcherLogStatistics(): Promise<WatcherLogStats> {
+    return await invoke('get_watcher_log_statistics');
+}
+
+export interface SupabaseValidationResult {
+    url_reachable: boolean;
+    key_valid: boolean;
+    tables_accessible: string[];
+    latency_ms: number;
+    error?: string;
+}
+
+export async function validateSupabaseConnection(): Promise<SupabaseValidationResult> {
+    return await invoke('validate_supabase_connection');
+}
+
+export interface LogFileValidation {
+    valid: boolean;
+    warnings: string[];
+    line_count: number;
+}
+
+export async function validateLogFile(path: string): Promise<LogFileValidation> {
+    return await invoke('validate_log_file', { path });
+}
+
+export async function getItemsFoundCount(): Promise<number> {
+    return await invoke('get_items_found_count');
+}
+
+export async function getInsuranceReturnsCount(): Promise<number> {
+    return await invoke('get_insurance_returns_count');
+}
+
+export interface TelemetryConsentPayload {
+    enabled: boolean;
+}
+
+export async function setTelemetryConsent(enabled: boolean): Promise<void> {
+    return await invoke('set_telemetry_consent', { enabled });
+}
+
+export type BsgQuestStatus =
+    | 'Locked'
+    | 'AvailableForStart'
+    | 'Started'
+    | 'AvailableForFinish'
+    | 'Success'
+    | 'Fail'
+    | 'FailRestartable'
+    | 'MarkedAsFailed'
+    | 'Expired'
+    | 'AvailableAfter';
+
+export interface QuestStatus {
+    id: string;
+    status: BsgQuestStatus;
+    started_at: string | null;
+}
+
+export interface TraderStanding {
+    loyalty_level: number;
+    standing: number;
+    sales_sum: number;
+    unlocked: boolean;
+}
+
+export interface ProfileImportSummary {
+    level: number;
+    quests_found: number;
+    importable_quests: QuestStatus[];
+    trader_standings: Record<string, TraderStanding>;
+    dry_run: boolean;
+}
+
+export async function importBsgProfile(path: string, userId: string, dryRun?: boolean): Promise<ProfileImportSummary> {
+    return await invoke('import_bsg_profile', { path, userId, dryRun });
+}